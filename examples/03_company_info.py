@@ -10,13 +10,16 @@ info = ticker.info
 for key, value in list(info.items())[:10]:  # Show first 10 items
     print(f"{key}: {value}")
 
-# Fast info - quick access to key metrics (dict)
+# Fast info - quick access to key metrics (FastInfo, fetched lazily)
 print("\n=== Fast Info ===")
 fast = ticker.fast_info
 for key, value in fast.items():
     print(f"{key}: {value}")
 
-# Calendar - upcoming events like earnings dates (dict)
+# Calendar - upcoming earnings/dividend dates (datetime.date) and estimate ranges (dict)
 print("\n=== Calendar ===")
 calendar = ticker.calendar
 print(calendar)
+
+# The same calendar as a one-row pl.DataFrame
+print(ticker.get_calendar(as_frame=True))
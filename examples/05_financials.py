@@ -28,6 +28,28 @@ print(ticker.cashflow)
 print("\n=== Cash Flow (Quarterly) ===")
 print(ticker.quarterly_cashflow)
 
-# Earnings summary (dict)
+# Earnings - yearly and quarterly revenue/earnings, EPS actual vs estimate
 print("\n=== Earnings ===")
 print(ticker.earnings)
+print(ticker.quarterly_earnings)
+print(ticker.earnings_history)
+
+# yfinance line items ("Total Revenue", "Net Income", ...) as rows, report dates as columns
+print("\n=== Income Statement (yfinance layout) ===")
+print(ticker.get_income_stmt(freq="quarterly", layout="yfinance"))
+
+# Trailing twelve months
+print("\n=== Income Statement (TTM) ===")
+print(ticker.ttm_income_stmt)
+
+# Any fundamentals timeseries as a long frame (date, key, value, currency)
+print("\n=== Fundamentals ===")
+print(ticker.get_fundamentals(["DilutedAverageShares", "StockBasedCompensation"], freq="quarterly"))
+
+# Derived ratios per report date (margins, ROE, ROIC, leverage, FCF yield, ...)
+print("\n=== Ratios ===")
+print(ticker.ratios(freq="yearly"))
+
+# Shares outstanding history, e.g. for point-in-time market cap
+print("\n=== Shares Outstanding ===")
+print(ticker.get_shares_full(start="2022-01-01"))
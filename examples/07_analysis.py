@@ -13,3 +13,23 @@ print(ticker.recommendations)
 # Individual analyst rating changes
 print("\n=== Upgrades/Downgrades ===")
 print(ticker.upgrades_downgrades)
+
+# Analyst estimates per period (0q, +1q, 0y, +1y)
+print("\n=== EPS Estimates ===")
+print(ticker.earnings_estimate)
+
+print("\n=== EPS Trend ===")
+print(ticker.eps_trend)
+
+print("\n=== EPS Revisions ===")
+print(ticker.eps_revisions)
+
+print("\n=== Growth Estimates ===")
+print(ticker.growth_estimates)
+
+# Consensus price targets (dict) and monthly rating counts (pl.DataFrame)
+print("\n=== Price Targets ===")
+print(ticker.analyst_price_targets)
+
+print("\n=== Recommendations Summary ===")
+print(ticker.recommendations_summary)
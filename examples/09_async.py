@@ -0,0 +1,24 @@
+"""Async API - Fetch many tickers concurrently with asyncio."""
+
+import asyncio
+
+import yfinance_pl as yf
+
+
+async def main():
+    symbols = ["AAPL", "MSFT", "GOOG", "AMZN"]
+
+    # Fetch history for all symbols concurrently
+    print("=== Last 5 days (concurrent) ===")
+    frames = await asyncio.gather(*(yf.AsyncTicker(s).history(period="5d") for s in symbols))
+    for symbol, df in zip(symbols, frames):
+        print(symbol)
+        print(df)
+
+    # Properties of Ticker are methods on AsyncTicker
+    print("\n=== Info ===")
+    info = await yf.AsyncTicker("AAPL").info()
+    print(info)
+
+
+asyncio.run(main())
@@ -1,11 +1,25 @@
 """yfinance-pl: Python wrapper for yfinance-rs using PyO3 and Polars."""
 
 from collections import namedtuple
-from typing import Annotated, Literal, TypedDict
+from datetime import date, datetime
+from typing import Annotated, Any, Literal, TypedDict
 
 import polars as pl
 
+from yfinance_pl._yfinance_pl import AsyncTicker as _RustAsyncTicker
+from yfinance_pl._yfinance_pl import Client, FastInfo
 from yfinance_pl._yfinance_pl import Ticker as _RustTicker
+from yfinance_pl._yfinance_pl import Tickers as _RustTickers
+from yfinance_pl._yfinance_pl import download as _rust_download
+from yfinance_pl.exceptions import (
+    AuthenticationError,
+    DataParseError,
+    InvalidParameterError,
+    NetworkError,
+    RateLimitError,
+    TickerNotFoundError,
+    YFinanceError,
+)
 
 # Type aliases for IDE autocompletion
 Period = Literal["1d", "5d", "1mo", "3mo", "6mo", "1y", "2y", "5y", "10y", "ytd", "max"]
@@ -15,60 +29,197 @@ Interval = Literal[
 
 # Date format type alias
 DateStr = Annotated[str, "Date string in YYYY-MM-DD format"]
+DateLike = DateStr | date | datetime
+GroupBy = Literal["ticker", "column"]
+StatementFreq = Literal["yearly", "quarterly", "trailing"]
+StatementLayout = Literal["long", "wide", "yfinance"]
+FundamentalsFreq = Literal["annual", "yearly", "quarterly", "trailing"]
+Endpoint = Literal[
+    "chart", "quote_summary", "quote", "options", "timeseries", "visualization", "cookie", "crumb"
+]
 
 
 # TypedDict definitions for structured return types
 class TickerInfo(TypedDict, total=False):
-    """Ticker information dictionary."""
+    """Ticker information dictionary, using yfinance's key names.
+
+    Merged from the quoteSummary assetProfile, summaryDetail, defaultKeyStatistics, financialData and price
+    modules. Numbers are ints or floats; ``get_info(decimal=True)`` returns every number, integers included,
+    as a ``decimal.Decimal`` of the exact value Yahoo sent. Dates are Unix timestamps in seconds.
+    """
 
+    # Identity
     symbol: str
+    isin: str | None
     shortName: str
-    isin: str
-    exchange: str
-    marketState: str
-    currency: str
-    regularMarketPrice: str
-    regularMarketOpen: str
-    regularMarketDayHigh: str
-    regularMarketDayLow: str
-    regularMarketPreviousClose: str
+    longName: str
+    quoteType: str
+    exchange: str  # Yahoo exchange code, e.g. "NMS"
+    exchangeName: str
+    marketState: str  # PREPRE, PRE, REGULAR, POST, POSTPOST or CLOSED
+    currency: str  # ISO 4217 code, e.g. "USD" (or "GBP" for a listing Yahoo quotes in pence)
+    pricesInMinorUnit: bool  # True when prices are in pence, cents or agorot (Yahoo's GBp, ZAc, ILA)
+    financialCurrency: str
+
+    # Profile (assetProfile)
+    address1: str
+    city: str
+    state: str
+    zip: str
+    country: str
+    phone: str
+    website: str
+    irWebsite: str
+    industry: str
+    industryKey: str
+    industryDisp: str
+    sector: str
+    sectorKey: str
+    sectorDisp: str
+    longBusinessSummary: str
+    fullTimeEmployees: int
+    companyOfficers: list[dict[str, Any]]
+    auditRisk: int
+    boardRisk: int
+    compensationRisk: int
+    shareHolderRightsRisk: int
+    overallRisk: int
+
+    # Prices (price, summaryDetail)
+    currentPrice: float
+    regularMarketPrice: float
+    regularMarketOpen: float
+    regularMarketDayHigh: float
+    regularMarketDayLow: float
+    regularMarketPreviousClose: float
+    regularMarketChange: float
+    regularMarketChangePercent: float
+    regularMarketTime: int
+    preMarketPrice: float
+    postMarketPrice: float
+    previousClose: float
+    open: float
+    dayLow: float
+    dayHigh: float
+    bid: float
+    ask: float
+    bidSize: int
+    askSize: int
+    fiftyTwoWeekLow: float
+    fiftyTwoWeekHigh: float
+    fiftyDayAverage: float
+    twoHundredDayAverage: float
+
+    # Volume
+    volume: int
     regularMarketVolume: int
     averageVolume: int
-    marketCap: str
+    averageVolume10days: int
+    averageDailyVolume10Day: int
+
+    # Valuation (summaryDetail, defaultKeyStatistics)
+    marketCap: int
+    enterpriseValue: int
+    beta: float
+    trailingPE: float
+    forwardPE: float
+    pegRatio: float
+    priceToBook: float
+    priceToSalesTrailing12Months: float
+    enterpriseToRevenue: float
+    enterpriseToEbitda: float
+    bookValue: float
+    trailingEps: float
+    forwardEps: float
+
+    # Dividends and splits
+    dividendRate: float
+    dividendYield: float
+    exDividendDate: int
+    payoutRatio: float
+    fiveYearAvgDividendYield: float
+    trailingAnnualDividendRate: float
+    trailingAnnualDividendYield: float
+    lastDividendValue: float
+    lastDividendDate: int
+    lastSplitFactor: str
+    lastSplitDate: int
+
+    # Shares
     sharesOutstanding: int
-    trailingEps: str
-    trailingPE: str
-    dividendYield: str
-    fiftyTwoWeekLow: str
-    fiftyTwoWeekHigh: str
-
-
-class FastInfo(TypedDict, total=False):
-    """Fast ticker information dictionary."""
-
-    symbol: str
-    name: str
-    exchange: str
-    currency: str
-    volume: int
+    floatShares: int
+    impliedSharesOutstanding: int
+    sharesShort: int
+    sharesShortPriorMonth: int
+    sharesPercentSharesOut: float
+    heldPercentInsiders: float
+    heldPercentInstitutions: float
+    shortRatio: float
+    shortPercentOfFloat: float
+
+    # Fiscal calendar
+    lastFiscalYearEnd: int
+    nextFiscalYearEnd: int
+    mostRecentQuarter: int
+
+    # Financials (financialData)
+    totalCash: int
+    totalCashPerShare: float
+    totalDebt: int
+    totalRevenue: int
+    revenuePerShare: float
+    grossProfits: int
+    ebitda: int
+    netIncomeToCommon: int
+    freeCashflow: int
+    operatingCashflow: int
+    quickRatio: float
+    currentRatio: float
+    debtToEquity: float
+    returnOnAssets: float
+    returnOnEquity: float
+    earningsGrowth: float
+    revenueGrowth: float
+    earningsQuarterlyGrowth: float
+    grossMargins: float
+    ebitdaMargins: float
+    operatingMargins: float
+    profitMargins: float
+
+    # Analysts (financialData)
+    targetHighPrice: float
+    targetLowPrice: float
+    targetMeanPrice: float
+    targetMedianPrice: float
+    recommendationMean: float
+    recommendationKey: str
+    numberOfAnalystOpinions: int
+
+
+class AnalystPriceTargets(TypedDict, total=False):
+    """Consensus analyst price targets next to the current price."""
+
+    current: float
+    high: float
+    low: float
+    mean: float
+    median: float
 
 
 class CalendarInfo(TypedDict, total=False):
     """Calendar events dictionary."""
 
     symbol: str
-    earningsDates: list[str]
-    exDividendDate: str
-    dividendDate: str
-
+    earningsDates: list[date]
+    exDividendDate: date
+    dividendDate: date
+    earningsHigh: float
+    earningsLow: float
+    earningsAverage: float
+    revenueHigh: float
+    revenueLow: float
+    revenueAverage: float
 
-class EarningsInfo(TypedDict):
-    """Earnings data dictionary."""
-
-    symbol: str
-    yearly_count: int
-    quarterly_count: int
-    quarterly_eps_count: int
 
 # Named tuple for option chain (yfinance-compatible)
 OptionChain = namedtuple("OptionChain", ["calls", "puts"])
@@ -88,8 +239,15 @@ def _wrap_option_chain(original_method):
 class Ticker:
     """Ticker class for fetching stock data (yfinance-compatible API)."""
 
-    def __init__(self, symbol):
-        self._ticker = _RustTicker(symbol)
+    def __init__(self, symbol, session: Client | None = None):
+        self._ticker = _RustTicker(symbol, session=session)
+
+    @classmethod
+    def _wrap(cls, rust_ticker):
+        """Wrap an existing Rust Ticker (e.g. one owned by Tickers)."""
+        obj = cls.__new__(cls)
+        obj._ticker = rust_ticker
+        return obj
 
     def __getattr__(self, name):
         return getattr(self._ticker, name)
@@ -101,8 +259,8 @@ class Ticker:
         self,
         period: Period | None = None,
         interval: Interval | None = None,
-        start: DateStr | None = None,
-        end: DateStr | None = None,
+        start: DateLike | None = None,
+        end: DateLike | None = None,
         prepost: bool = False,
         auto_adjust: bool = True,
         actions: bool = True,
@@ -112,14 +270,17 @@ class Ticker:
         Args:
             period: Data period (1d, 5d, 1mo, 3mo, 6mo, 1y, 2y, 5y, 10y, ytd, max)
             interval: Data interval (1m, 2m, 5m, 15m, 30m, 60m, 90m, 1h, 1d, 5d, 1wk, 1mo, 3mo)
-            start: Start date (YYYY-MM-DD) - not yet implemented
-            end: End date (YYYY-MM-DD) - not yet implemented
+            start: Start date, inclusive (YYYY-MM-DD string, date or datetime)
+            end: End date, exclusive (YYYY-MM-DD string, date or datetime)
             prepost: Include pre and post market data
             auto_adjust: Adjust prices for splits and dividends
-            actions: Include dividends and stock splits
+            actions: Add dividends, stock_splits and capital_gains columns
 
         Returns:
             pl.DataFrame: Historical OHLCV data with date column
+
+        Raises:
+            InvalidParameterError: If period/interval is unknown or the window is not served for the interval
         """
         return self._ticker.history(
             period=period,
@@ -144,15 +305,180 @@ class Ticker:
         return OptionChain(calls=calls, puts=puts)
 
 
+class Tickers:
+    """Collection of tickers whose properties are fetched concurrently.
+
+    Example:
+        >>> t = Tickers("AAPL MSFT GOOG")
+        >>> t.tickers["AAPL"].info
+        >>> t.history(period="1mo")  # long frame with a ticker column
+    """
+
+    def __init__(self, tickers: str | list[str], threads: int | None = None, session: Client | None = None):
+        self._tickers = _RustTickers(tickers, threads=threads, session=session)
+        self.tickers = {symbol: Ticker._wrap(t) for symbol, t in self._tickers.tickers.items()}
+
+    def __getattr__(self, name):
+        return getattr(self._tickers, name)
+
+    def __repr__(self):
+        return repr(self._tickers)
+
+    def history(
+        self,
+        period: Period | None = None,
+        interval: Interval | None = None,
+        start: DateLike | None = None,
+        end: DateLike | None = None,
+        group_by: GroupBy | None = None,
+        prepost: bool = False,
+        auto_adjust: bool = True,
+        actions: bool = True,
+    ) -> pl.DataFrame:
+        """Get historical OHLCV data for all symbols as one Polars DataFrame.
+
+        Takes the same arguments as download() (except tickers and threads).
+        """
+        return self._tickers.history(
+            period=period,
+            interval=interval,
+            start=start,
+            end=end,
+            group_by=group_by,
+            prepost=prepost,
+            auto_adjust=auto_adjust,
+            actions=actions,
+        )
+
+
+class AsyncTicker:
+    """Asyncio variant of Ticker: every data method is a coroutine.
+
+    Example:
+        >>> t = AsyncTicker("AAPL")
+        >>> df = await t.history(period="1mo")
+        >>> infos = await asyncio.gather(*(AsyncTicker(s).info() for s in symbols))
+    """
+
+    def __init__(self, symbol, session: Client | None = None):
+        self._ticker = _RustAsyncTicker(symbol, session=session)
+
+    def __getattr__(self, name):
+        return getattr(self._ticker, name)
+
+    def __repr__(self):
+        return repr(self._ticker)
+
+    async def history(
+        self,
+        period: Period | None = None,
+        interval: Interval | None = None,
+        start: DateLike | None = None,
+        end: DateLike | None = None,
+        prepost: bool = False,
+        auto_adjust: bool = True,
+        actions: bool = True,
+    ) -> pl.DataFrame:
+        """Get historical OHLCV data as a Polars DataFrame.
+
+        Takes the same arguments as Ticker.history.
+        """
+        return await self._ticker.history(
+            period=period,
+            interval=interval,
+            start=start,
+            end=end,
+            prepost=prepost,
+            auto_adjust=auto_adjust,
+            actions=actions,
+        )
+
+    async def option_chain(self, date: DateStr | None = None) -> OptionChain:
+        """Get option chain for a specific expiration date.
+
+        Args:
+            date: Expiration date in YYYY-MM-DD format (optional)
+
+        Returns:
+            OptionChain: Named tuple with calls and puts DataFrames
+        """
+        calls, puts = await self._ticker.option_chain(date)
+        return OptionChain(calls=calls, puts=puts)
+
+
+def download(
+    tickers: str | list[str],
+    period: Period | None = None,
+    interval: Interval | None = None,
+    start: DateLike | None = None,
+    end: DateLike | None = None,
+    group_by: GroupBy | None = None,
+    threads: int | None = None,
+    prepost: bool = False,
+    auto_adjust: bool = True,
+    actions: bool = True,
+    session: Client | None = None,
+) -> pl.DataFrame:
+    """Download price history for several tickers concurrently.
+
+    Args:
+        tickers: Symbols as a list or a space/comma separated string (e.g. "AAPL MSFT")
+        period: Data period (1d, 5d, 1mo, 3mo, 6mo, 1y, 2y, 5y, 10y, ytd, max)
+        interval: Data interval (1m, 2m, 5m, 15m, 30m, 60m, 90m, 1h, 1d, 5d, 1wk, 1mo, 3mo)
+        start: Start date, inclusive (YYYY-MM-DD string, date or datetime)
+        end: End date, exclusive (YYYY-MM-DD string, date or datetime)
+        group_by: None for a long frame with a ``ticker`` column; "ticker" or "column" for a wide
+            frame joined on date with ``{ticker}_{field}`` or ``{field}_{ticker}`` columns
+        threads: Maximum number of concurrent requests (default 8)
+        prepost: Include pre and post market data
+        auto_adjust: Adjust prices for splits and dividends
+        actions: Add dividends, stock_splits and capital_gains columns
+        session: Client to use instead of the shared default
+
+    Returns:
+        pl.DataFrame: Combined price history. Failed symbols are skipped with a warning.
+    """
+    return _rust_download(
+        tickers,
+        period=period,
+        interval=interval,
+        start=start,
+        end=end,
+        group_by=group_by,
+        threads=threads,
+        prepost=prepost,
+        auto_adjust=auto_adjust,
+        actions=actions,
+        session=session,
+    )
+
+
 __all__ = [
     "Ticker",
+    "AsyncTicker",
+    "Tickers",
+    "Client",
+    "download",
     "OptionChain",
+    "YFinanceError",
+    "TickerNotFoundError",
+    "RateLimitError",
+    "NetworkError",
+    "AuthenticationError",
+    "DataParseError",
+    "InvalidParameterError",
     "Period",
     "Interval",
     "DateStr",
+    "DateLike",
+    "GroupBy",
+    "StatementFreq",
+    "StatementLayout",
+    "FundamentalsFreq",
+    "Endpoint",
     "TickerInfo",
     "FastInfo",
     "CalendarInfo",
-    "EarningsInfo",
+    "AnalystPriceTargets",
 ]
 __version__ = "0.7.2.2"
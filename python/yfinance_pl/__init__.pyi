@@ -1,68 +1,273 @@
 """Type stubs for yfinance-pl."""
 
-from typing import Annotated, Literal, NamedTuple, TypedDict
+from collections.abc import Iterator
+from datetime import date, datetime
+from typing import Annotated, Any, Literal, NamedTuple, TypedDict, overload
 
 import polars as pl
 
+from yfinance_pl.exceptions import (
+    AuthenticationError as AuthenticationError,
+    DataParseError as DataParseError,
+    InvalidParameterError as InvalidParameterError,
+    NetworkError as NetworkError,
+    RateLimitError as RateLimitError,
+    TickerNotFoundError as TickerNotFoundError,
+    YFinanceError as YFinanceError,
+)
+
 # Type aliases
 Period = Literal["1d", "5d", "1mo", "3mo", "6mo", "1y", "2y", "5y", "10y", "ytd", "max"]
 Interval = Literal[
     "1m", "2m", "5m", "15m", "30m", "60m", "90m", "1h", "1d", "5d", "1wk", "1mo", "3mo"
 ]
 DateStr = Annotated[str, "Date string in YYYY-MM-DD format"]
+DateLike = DateStr | date | datetime
+GroupBy = Literal["ticker", "column"]
+StatementFreq = Literal["yearly", "quarterly", "trailing"]
+StatementLayout = Literal["long", "wide", "yfinance"]
+FundamentalsFreq = Literal["annual", "yearly", "quarterly", "trailing"]
+Endpoint = Literal[
+    "chart", "quote_summary", "quote", "options", "timeseries", "visualization", "cookie", "crumb"
+]
 
 
 class TickerInfo(TypedDict, total=False):
-    """Ticker information dictionary."""
+    """Ticker information dictionary, using yfinance's key names.
+
+    Merged from the quoteSummary assetProfile, summaryDetail, defaultKeyStatistics, financialData and price
+    modules. Numbers are ints or floats; ``get_info(decimal=True)`` returns every number, integers included,
+    as a ``decimal.Decimal`` of the exact value Yahoo sent. Dates are Unix timestamps in seconds.
+    """
 
+    # Identity
     symbol: str
+    isin: str | None
     shortName: str
-    isin: str
-    exchange: str
-    marketState: str
-    currency: str
-    regularMarketPrice: str
-    regularMarketOpen: str
-    regularMarketDayHigh: str
-    regularMarketDayLow: str
-    regularMarketPreviousClose: str
+    longName: str
+    quoteType: str
+    exchange: str  # Yahoo exchange code, e.g. "NMS"
+    exchangeName: str
+    marketState: str  # PREPRE, PRE, REGULAR, POST, POSTPOST or CLOSED
+    currency: str  # ISO 4217 code, e.g. "USD" (or "GBP" for a listing Yahoo quotes in pence)
+    pricesInMinorUnit: bool  # True when prices are in pence, cents or agorot (Yahoo's GBp, ZAc, ILA)
+    financialCurrency: str
+
+    # Profile (assetProfile)
+    address1: str
+    city: str
+    state: str
+    zip: str
+    country: str
+    phone: str
+    website: str
+    irWebsite: str
+    industry: str
+    industryKey: str
+    industryDisp: str
+    sector: str
+    sectorKey: str
+    sectorDisp: str
+    longBusinessSummary: str
+    fullTimeEmployees: int
+    companyOfficers: list[dict[str, Any]]
+    auditRisk: int
+    boardRisk: int
+    compensationRisk: int
+    shareHolderRightsRisk: int
+    overallRisk: int
+
+    # Prices (price, summaryDetail)
+    currentPrice: float
+    regularMarketPrice: float
+    regularMarketOpen: float
+    regularMarketDayHigh: float
+    regularMarketDayLow: float
+    regularMarketPreviousClose: float
+    regularMarketChange: float
+    regularMarketChangePercent: float
+    regularMarketTime: int
+    preMarketPrice: float
+    postMarketPrice: float
+    previousClose: float
+    open: float
+    dayLow: float
+    dayHigh: float
+    bid: float
+    ask: float
+    bidSize: int
+    askSize: int
+    fiftyTwoWeekLow: float
+    fiftyTwoWeekHigh: float
+    fiftyDayAverage: float
+    twoHundredDayAverage: float
+
+    # Volume
+    volume: int
     regularMarketVolume: int
     averageVolume: int
-    marketCap: str
+    averageVolume10days: int
+    averageDailyVolume10Day: int
+
+    # Valuation (summaryDetail, defaultKeyStatistics)
+    marketCap: int
+    enterpriseValue: int
+    beta: float
+    trailingPE: float
+    forwardPE: float
+    pegRatio: float
+    priceToBook: float
+    priceToSalesTrailing12Months: float
+    enterpriseToRevenue: float
+    enterpriseToEbitda: float
+    bookValue: float
+    trailingEps: float
+    forwardEps: float
+
+    # Dividends and splits
+    dividendRate: float
+    dividendYield: float
+    exDividendDate: int
+    payoutRatio: float
+    fiveYearAvgDividendYield: float
+    trailingAnnualDividendRate: float
+    trailingAnnualDividendYield: float
+    lastDividendValue: float
+    lastDividendDate: int
+    lastSplitFactor: str
+    lastSplitDate: int
+
+    # Shares
     sharesOutstanding: int
-    trailingEps: str
-    trailingPE: str
-    dividendYield: str
-    fiftyTwoWeekLow: str
-    fiftyTwoWeekHigh: str
+    floatShares: int
+    impliedSharesOutstanding: int
+    sharesShort: int
+    sharesShortPriorMonth: int
+    sharesPercentSharesOut: float
+    heldPercentInsiders: float
+    heldPercentInstitutions: float
+    shortRatio: float
+    shortPercentOfFloat: float
+
+    # Fiscal calendar
+    lastFiscalYearEnd: int
+    nextFiscalYearEnd: int
+    mostRecentQuarter: int
+
+    # Financials (financialData)
+    totalCash: int
+    totalCashPerShare: float
+    totalDebt: int
+    totalRevenue: int
+    revenuePerShare: float
+    grossProfits: int
+    ebitda: int
+    netIncomeToCommon: int
+    freeCashflow: int
+    operatingCashflow: int
+    quickRatio: float
+    currentRatio: float
+    debtToEquity: float
+    returnOnAssets: float
+    returnOnEquity: float
+    earningsGrowth: float
+    revenueGrowth: float
+    earningsQuarterlyGrowth: float
+    grossMargins: float
+    ebitdaMargins: float
+    operatingMargins: float
+    profitMargins: float
+
+    # Analysts (financialData)
+    targetHighPrice: float
+    targetLowPrice: float
+    targetMeanPrice: float
+    targetMedianPrice: float
+    recommendationMean: float
+    recommendationKey: str
+    numberOfAnalystOpinions: int
+
+
+class FastInfo:
+    """Quick-access price metrics, fetched on first access.
+
+    Values are available as attributes (``fi.last_price``) or by yfinance key (``fi["lastPrice"]``).
+    """
 
+    @property
+    def currency(self) -> str | None: ...
+    @property
+    def day_high(self) -> float | None: ...
+    @property
+    def day_low(self) -> float | None: ...
+    @property
+    def exchange(self) -> str | None: ...
+    @property
+    def fifty_day_average(self) -> float | None: ...
+    @property
+    def last_price(self) -> float | None: ...
+    @property
+    def last_volume(self) -> int | None: ...
+    @property
+    def market_cap(self) -> float | None: ...
+    @property
+    def open(self) -> float | None: ...
+    @property
+    def previous_close(self) -> float | None: ...
+    @property
+    def prices_in_minor_unit(self) -> bool | None: ...
+    @property
+    def quote_type(self) -> str | None: ...
+    @property
+    def regular_market_previous_close(self) -> float | None: ...
+    @property
+    def shares(self) -> int | None: ...
+    @property
+    def ten_day_average_volume(self) -> int | None: ...
+    @property
+    def three_month_average_volume(self) -> int | None: ...
+    @property
+    def timezone(self) -> str | None: ...
+    @property
+    def two_hundred_day_average(self) -> float | None: ...
+    @property
+    def year_change(self) -> float | None: ...
+    @property
+    def year_high(self) -> float | None: ...
+    @property
+    def year_low(self) -> float | None: ...
+    def keys(self) -> list[str]: ...
+    def items(self) -> list[tuple[str, Any]]: ...
+    def get(self, key: str, default: Any = None) -> Any: ...
+    def __getitem__(self, key: str) -> Any: ...
+    def __contains__(self, key: str) -> bool: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[str]: ...
 
-class FastInfo(TypedDict, total=False):
-    """Fast ticker information dictionary."""
 
-    symbol: str
-    name: str
-    exchange: str
-    currency: str
-    volume: int
+class AnalystPriceTargets(TypedDict, total=False):
+    """Consensus analyst price targets next to the current price."""
+
+    current: float
+    high: float
+    low: float
+    mean: float
+    median: float
 
 
 class CalendarInfo(TypedDict, total=False):
     """Calendar events dictionary."""
 
     symbol: str
-    earningsDates: list[str]
-    exDividendDate: str
-    dividendDate: str
-
-
-class EarningsInfo(TypedDict):
-    """Earnings data dictionary."""
-
-    symbol: str
-    yearly_count: int
-    quarterly_count: int
-    quarterly_eps_count: int
+    earningsDates: list[date]
+    exDividendDate: date
+    dividendDate: date
+    earningsHigh: float
+    earningsLow: float
+    earningsAverage: float
+    revenueHigh: float
+    revenueLow: float
+    revenueAverage: float
 
 
 class OptionChain(NamedTuple):
@@ -72,18 +277,34 @@ class OptionChain(NamedTuple):
     puts: pl.DataFrame
 
 
+class Client:
+    """HTTP client configuration shared by Ticker, AsyncTicker, Tickers and download()."""
+
+    def __init__(
+        self,
+        proxy: str | None = None,
+        timeout: float | None = None,
+        connect_timeout: float | None = None,
+        retries: int | None = None,
+        backoff: float | None = None,
+        user_agent: str | None = None,
+        base_url: str | None = None,
+        base_urls: dict[Endpoint, str] | None = None,
+    ) -> None: ...
+
+
 class Ticker:
     """Ticker class for fetching stock data (yfinance-compatible API)."""
 
-    def __init__(self, symbol: str) -> None: ...
+    def __init__(self, symbol: str, session: Client | None = None) -> None: ...
 
     # Price History
     def history(
         self,
         period: Period | None = None,
         interval: Interval | None = None,
-        start: DateStr | None = None,
-        end: DateStr | None = None,
+        start: DateLike | None = None,
+        end: DateLike | None = None,
         prepost: bool = False,
         auto_adjust: bool = True,
         actions: bool = True,
@@ -97,14 +318,30 @@ class Ticker:
         """Get ticker info as a dictionary."""
         ...
 
+    @overload
+    def get_info(self, decimal: Literal[False] = False) -> TickerInfo: ...
+    @overload
+    def get_info(self, decimal: Literal[True]) -> dict[str, Any]: ...
+    def get_info(self, decimal: bool = False) -> TickerInfo | dict[str, Any]:
+        """Get ticker info, with every number as an exact decimal.Decimal when decimal=True."""
+        ...
+
     @property
     def fast_info(self) -> FastInfo:
-        """Get fast info as a dictionary."""
+        """Get quick-access price metrics, fetched lazily on first access and cached on this Ticker."""
         ...
 
     @property
     def calendar(self) -> CalendarInfo:
-        """Get calendar events as a dictionary."""
+        """Get calendar events (dates as datetime.date, EPS and revenue estimate ranges) as a dictionary."""
+        ...
+
+    @overload
+    def get_calendar(self, as_frame: Literal[False] = False) -> CalendarInfo: ...
+    @overload
+    def get_calendar(self, as_frame: Literal[True]) -> pl.DataFrame: ...
+    def get_calendar(self, as_frame: bool = False) -> CalendarInfo | pl.DataFrame:
+        """Get calendar events as a dictionary, or as a one-row Polars DataFrame when as_frame=True."""
         ...
 
     # Dividends & Corporate Actions
@@ -120,7 +357,7 @@ class Ticker:
 
     @property
     def actions(self) -> pl.DataFrame:
-        """Get corporate actions (dividends + splits) as a Polars DataFrame."""
+        """Get corporate actions (dividends, splits and capital gains) as a Polars DataFrame."""
         ...
 
     @property
@@ -160,8 +397,100 @@ class Ticker:
         ...
 
     @property
-    def earnings(self) -> EarningsInfo:
-        """Get earnings data as a dictionary."""
+    def ttm_income_stmt(self) -> pl.DataFrame:
+        """Get the trailing twelve months income statement (one row per date, one column per line item)."""
+        ...
+
+    @property
+    def ttm_cashflow(self) -> pl.DataFrame:
+        """Get the trailing twelve months cash flow statement (one row per date, one column per line item)."""
+        ...
+
+    def get_income_stmt(self, freq: StatementFreq = "yearly", layout: StatementLayout = "wide") -> pl.DataFrame:
+        """Get the income statement with yfinance line-item names (e.g. "Total Revenue") as a Polars DataFrame.
+
+        layout="long" gives (date, line_item, value) rows, "wide" one row per report date and
+        "yfinance" one row per line item with one column per report date, newest first.
+        """
+        ...
+
+    def get_balance_sheet(
+        self, freq: Literal["yearly", "quarterly"] = "yearly", layout: StatementLayout = "wide"
+    ) -> pl.DataFrame:
+        """Get the balance sheet with yfinance line-item names (e.g. "Total Assets") as a Polars DataFrame.
+
+        freq="trailing" is not available for the balance sheet. layout="long" gives (date, line_item, value)
+        rows, "wide" one row per report date and "yfinance" one row per line item with one column per report
+        date, newest first.
+        """
+        ...
+
+    def get_cashflow(self, freq: StatementFreq = "yearly", layout: StatementLayout = "wide") -> pl.DataFrame:
+        """Get the cash flow statement with yfinance line-item names (e.g. "Free Cash Flow") as a Polars DataFrame.
+
+        layout="long" gives (date, line_item, value) rows, "wide" one row per report date and
+        "yfinance" one row per line item with one column per report date, newest first.
+        """
+        ...
+
+    def get_fundamentals(
+        self,
+        keys: list[str] | None = None,
+        freq: FundamentalsFreq = "annual",
+        start: DateLike | None = None,
+        end: DateLike | None = None,
+    ) -> pl.DataFrame:
+        """Get fundamentals timeseries as a long Polars DataFrame (date, key, value, currency).
+
+        keys are Yahoo line items such as "DilutedAverageShares", "StockBasedCompensation" or
+        "TangibleBookValue"; by default every income statement, balance sheet and cash flow item is fetched.
+        """
+        ...
+
+    def ratios(self, freq: Literal["yearly", "quarterly"] = "yearly") -> pl.DataFrame:
+        """Get financial ratios per report date (newest first) as a Polars DataFrame.
+
+        Columns: date, gross_margin, operating_margin, net_margin, roe, roa, roic, current_ratio,
+        debt_to_equity, interest_coverage, fcf_yield, asset_turnover. A ratio is null when a line item
+        it needs is missing or its denominator is zero; fcf_yield uses the current market cap and is null
+        except for the latest period. With freq="quarterly", roe, roa, roic, asset_turnover and fcf_yield
+        use trailing-twelve-month flows.
+        """
+        ...
+
+    def get_shares_full(self, start: DateLike | None = None, end: DateLike | None = None) -> pl.DataFrame:
+        """Get the history of shares outstanding as a Polars DataFrame (date, shares).
+
+        Defaults to the 548 days before end (now), as in yfinance; rows are in ascending date order.
+        """
+        ...
+
+    @property
+    def earnings(self) -> pl.DataFrame:
+        """Get yearly revenue and earnings (year, revenue, earnings) as a Polars DataFrame."""
+        ...
+
+    @property
+    def quarterly_earnings(self) -> pl.DataFrame:
+        """Get quarterly revenue and earnings (quarter, revenue, earnings) as a Polars DataFrame."""
+        ...
+
+    @property
+    def earnings_history(self) -> pl.DataFrame:
+        """Get reported vs estimated EPS for recent quarters as a Polars DataFrame."""
+        ...
+
+    @property
+    def earnings_dates(self) -> pl.DataFrame:
+        """Get the last 12 past and upcoming earnings dates as a Polars DataFrame."""
+        ...
+
+    def get_earnings_dates(self, limit: int = 12) -> pl.DataFrame:
+        """Get up to `limit` past and upcoming earnings dates, newest first.
+
+        Columns: earnings_date (in the exchange timezone), eps_estimate, reported_eps, surprise_percent.
+        Scheduled reports have null reported_eps and surprise_percent.
+        """
         ...
 
     # Shareholders
@@ -201,6 +530,41 @@ class Ticker:
         """Get analyst upgrades/downgrades history as a Polars DataFrame."""
         ...
 
+    @property
+    def analyst_price_targets(self) -> AnalystPriceTargets:
+        """Get analyst price targets (current, high, low, mean, median) as a dictionary."""
+        ...
+
+    @property
+    def recommendations_summary(self) -> pl.DataFrame:
+        """Get strong_buy/buy/hold/sell/strong_sell counts per month (period 0m, -1m, ...) as a Polars DataFrame."""
+        ...
+
+    @property
+    def earnings_estimate(self) -> pl.DataFrame:
+        """Get EPS estimates (avg, low, high, year_ago_eps, number_of_analysts, growth) per period."""
+        ...
+
+    @property
+    def revenue_estimate(self) -> pl.DataFrame:
+        """Get revenue estimates (avg, low, high, number_of_analysts, year_ago_revenue, growth) per period."""
+        ...
+
+    @property
+    def eps_trend(self) -> pl.DataFrame:
+        """Get the consensus EPS estimate now and 7, 30, 60 and 90 days ago per period."""
+        ...
+
+    @property
+    def eps_revisions(self) -> pl.DataFrame:
+        """Get upward and downward EPS revision counts over the last 7 and 30 days per period."""
+        ...
+
+    @property
+    def growth_estimates(self) -> pl.DataFrame:
+        """Get expected growth of the stock (stock_trend) and its index (index_trend) per period."""
+        ...
+
     # Options
     @property
     def options(self) -> list[str]:
@@ -217,15 +581,367 @@ class Ticker:
         ...
 
 
+class Tickers:
+    """Collection of tickers whose properties are fetched concurrently."""
+
+    tickers: dict[str, Ticker]
+
+    def __init__(
+        self, tickers: str | list[str], threads: int | None = None, session: Client | None = None
+    ) -> None: ...
+
+    @property
+    def symbols(self) -> list[str]:
+        """Symbols in this collection."""
+        ...
+
+    def history(
+        self,
+        period: Period | None = None,
+        interval: Interval | None = None,
+        start: DateLike | None = None,
+        end: DateLike | None = None,
+        group_by: GroupBy | None = None,
+        prepost: bool = False,
+        auto_adjust: bool = True,
+        actions: bool = True,
+    ) -> pl.DataFrame:
+        """Get historical OHLCV data for all symbols as one Polars DataFrame."""
+        ...
+
+    @property
+    def info(self) -> dict[str, TickerInfo]:
+        """Get ticker info for all symbols, keyed by symbol."""
+        ...
+
+    @property
+    def fast_info(self) -> dict[str, FastInfo]:
+        """Get lazily loaded FastInfo objects for all symbols, keyed by symbol."""
+        ...
+
+    @property
+    def dividends(self) -> pl.DataFrame:
+        """Get dividend history for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def splits(self) -> pl.DataFrame:
+        """Get stock splits history for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def actions(self) -> pl.DataFrame:
+        """Get corporate actions for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def capital_gains(self) -> pl.DataFrame:
+        """Get capital gains for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def income_stmt(self) -> pl.DataFrame:
+        """Get annual income statements for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def quarterly_income_stmt(self) -> pl.DataFrame:
+        """Get quarterly income statements for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def balance_sheet(self) -> pl.DataFrame:
+        """Get annual balance sheets for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def quarterly_balance_sheet(self) -> pl.DataFrame:
+        """Get quarterly balance sheets for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def cashflow(self) -> pl.DataFrame:
+        """Get annual cash flow statements for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def quarterly_cashflow(self) -> pl.DataFrame:
+        """Get quarterly cash flow statements for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def recommendations(self) -> pl.DataFrame:
+        """Get analyst recommendations for all symbols as a Polars DataFrame."""
+        ...
+
+    @property
+    def upgrades_downgrades(self) -> pl.DataFrame:
+        """Get analyst upgrades/downgrades for all symbols as a Polars DataFrame."""
+        ...
+
+
+class AsyncTicker:
+    """Asyncio variant of Ticker: every data method is a coroutine."""
+
+    def __init__(self, symbol: str, session: Client | None = None) -> None: ...
+
+    # Price History
+    async def history(
+        self,
+        period: Period | None = None,
+        interval: Interval | None = None,
+        start: DateLike | None = None,
+        end: DateLike | None = None,
+        prepost: bool = False,
+        auto_adjust: bool = True,
+        actions: bool = True,
+    ) -> pl.DataFrame:
+        """Get historical OHLCV data as a Polars DataFrame."""
+        ...
+
+    # Company Info
+    @overload
+    async def info(self, decimal: Literal[False] = False) -> TickerInfo: ...
+    @overload
+    async def info(self, decimal: Literal[True]) -> dict[str, Any]: ...
+    async def info(self, decimal: bool = False) -> TickerInfo | dict[str, Any]:
+        """Get ticker info as a dictionary, with every number as an exact decimal.Decimal when decimal=True."""
+        ...
+
+    async def fast_info(self) -> FastInfo:
+        """Get quick-access price metrics with every value already loaded."""
+        ...
+
+    async def calendar(self) -> CalendarInfo:
+        """Get calendar events as a dictionary."""
+        ...
+
+    @overload
+    async def get_calendar(self, as_frame: Literal[False] = False) -> CalendarInfo: ...
+    @overload
+    async def get_calendar(self, as_frame: Literal[True]) -> pl.DataFrame: ...
+    async def get_calendar(self, as_frame: bool = False) -> CalendarInfo | pl.DataFrame:
+        """Get calendar events as a dictionary, or as a one-row Polars DataFrame when as_frame=True."""
+        ...
+
+    # Dividends & Corporate Actions
+    async def dividends(self) -> pl.DataFrame:
+        """Get dividend history as a Polars DataFrame."""
+        ...
+
+    async def splits(self) -> pl.DataFrame:
+        """Get stock splits history as a Polars DataFrame."""
+        ...
+
+    async def actions(self) -> pl.DataFrame:
+        """Get corporate actions (dividends, splits and capital gains) as a Polars DataFrame."""
+        ...
+
+    async def capital_gains(self) -> pl.DataFrame:
+        """Get capital gains as a Polars DataFrame."""
+        ...
+
+    # Financial Statements
+    async def income_stmt(self) -> pl.DataFrame:
+        """Get annual income statement as a Polars DataFrame."""
+        ...
+
+    async def quarterly_income_stmt(self) -> pl.DataFrame:
+        """Get quarterly income statement as a Polars DataFrame."""
+        ...
+
+    async def balance_sheet(self) -> pl.DataFrame:
+        """Get annual balance sheet as a Polars DataFrame."""
+        ...
+
+    async def quarterly_balance_sheet(self) -> pl.DataFrame:
+        """Get quarterly balance sheet as a Polars DataFrame."""
+        ...
+
+    async def cashflow(self) -> pl.DataFrame:
+        """Get annual cash flow statement as a Polars DataFrame."""
+        ...
+
+    async def quarterly_cashflow(self) -> pl.DataFrame:
+        """Get quarterly cash flow statement as a Polars DataFrame."""
+        ...
+
+    async def ttm_income_stmt(self) -> pl.DataFrame:
+        """Get the trailing twelve months income statement as a Polars DataFrame."""
+        ...
+
+    async def ttm_cashflow(self) -> pl.DataFrame:
+        """Get the trailing twelve months cash flow statement as a Polars DataFrame."""
+        ...
+
+    async def get_income_stmt(self, freq: StatementFreq = "yearly", layout: StatementLayout = "wide") -> pl.DataFrame:
+        """Get the income statement with yfinance line-item names as a Polars DataFrame."""
+        ...
+
+    async def get_balance_sheet(
+        self, freq: Literal["yearly", "quarterly"] = "yearly", layout: StatementLayout = "wide"
+    ) -> pl.DataFrame:
+        """Get the balance sheet with yfinance line-item names as a Polars DataFrame."""
+        ...
+
+    async def get_cashflow(self, freq: StatementFreq = "yearly", layout: StatementLayout = "wide") -> pl.DataFrame:
+        """Get the cash flow statement with yfinance line-item names as a Polars DataFrame."""
+        ...
+
+    async def get_fundamentals(
+        self,
+        keys: list[str] | None = None,
+        freq: FundamentalsFreq = "annual",
+        start: DateLike | None = None,
+        end: DateLike | None = None,
+    ) -> pl.DataFrame:
+        """Get fundamentals timeseries as a long Polars DataFrame (date, key, value, currency)."""
+        ...
+
+    async def ratios(self, freq: Literal["yearly", "quarterly"] = "yearly") -> pl.DataFrame:
+        """Get financial ratios per report date (newest first) as a Polars DataFrame."""
+        ...
+
+    async def get_shares_full(self, start: DateLike | None = None, end: DateLike | None = None) -> pl.DataFrame:
+        """Get the history of shares outstanding as a Polars DataFrame (date, shares)."""
+        ...
+
+    async def earnings(self) -> pl.DataFrame:
+        """Get yearly revenue and earnings (year, revenue, earnings) as a Polars DataFrame."""
+        ...
+
+    async def quarterly_earnings(self) -> pl.DataFrame:
+        """Get quarterly revenue and earnings (quarter, revenue, earnings) as a Polars DataFrame."""
+        ...
+
+    async def earnings_history(self) -> pl.DataFrame:
+        """Get reported vs estimated EPS for recent quarters as a Polars DataFrame."""
+        ...
+
+    async def earnings_dates(self) -> pl.DataFrame:
+        """Get past and upcoming earnings dates with EPS estimates and surprises as a Polars DataFrame."""
+        ...
+
+    async def get_earnings_dates(self, limit: int = 12) -> pl.DataFrame:
+        """Get up to `limit` past and upcoming earnings dates, newest first."""
+        ...
+
+    # Shareholders
+    async def major_holders(self) -> pl.DataFrame:
+        """Get major holders breakdown as a Polars DataFrame."""
+        ...
+
+    async def institutional_holders(self) -> pl.DataFrame:
+        """Get institutional holders as a Polars DataFrame."""
+        ...
+
+    async def mutualfund_holders(self) -> pl.DataFrame:
+        """Get mutual fund holders as a Polars DataFrame."""
+        ...
+
+    async def insider_transactions(self) -> pl.DataFrame:
+        """Get insider transactions as a Polars DataFrame."""
+        ...
+
+    async def insider_roster_holders(self) -> pl.DataFrame:
+        """Get insider roster holders as a Polars DataFrame."""
+        ...
+
+    # Analyst Recommendations
+    async def recommendations(self) -> pl.DataFrame:
+        """Get analyst recommendations as a Polars DataFrame."""
+        ...
+
+    async def upgrades_downgrades(self) -> pl.DataFrame:
+        """Get analyst upgrades/downgrades history as a Polars DataFrame."""
+        ...
+
+    async def analyst_price_targets(self) -> AnalystPriceTargets:
+        """Get analyst price targets (current, high, low, mean, median) as a dictionary."""
+        ...
+
+    async def recommendations_summary(self) -> pl.DataFrame:
+        """Get strong_buy/buy/hold/sell/strong_sell counts per month as a Polars DataFrame."""
+        ...
+
+    async def earnings_estimate(self) -> pl.DataFrame:
+        """Get EPS estimates (avg, low, high, year_ago_eps, number_of_analysts, growth) per period."""
+        ...
+
+    async def revenue_estimate(self) -> pl.DataFrame:
+        """Get revenue estimates (avg, low, high, number_of_analysts, year_ago_revenue, growth) per period."""
+        ...
+
+    async def eps_trend(self) -> pl.DataFrame:
+        """Get the consensus EPS estimate now and 7, 30, 60 and 90 days ago per period."""
+        ...
+
+    async def eps_revisions(self) -> pl.DataFrame:
+        """Get upward and downward EPS revision counts over the last 7 and 30 days per period."""
+        ...
+
+    async def growth_estimates(self) -> pl.DataFrame:
+        """Get expected growth of the stock (stock_trend) and its index (index_trend) per period."""
+        ...
+
+    # Options
+    async def options(self) -> list[str]:
+        """Get available option expiration dates as a list of strings (YYYY-MM-DD)."""
+        ...
+
+    async def option_chain(self, date: DateStr | None = None) -> OptionChain:
+        """Get option chain for a specific expiration date."""
+        ...
+
+    # Other
+    async def get_isin(self) -> str | None:
+        """Get the ISIN for this ticker."""
+        ...
+
+
+def download(
+    tickers: str | list[str],
+    period: Period | None = None,
+    interval: Interval | None = None,
+    start: DateLike | None = None,
+    end: DateLike | None = None,
+    group_by: GroupBy | None = None,
+    threads: int | None = None,
+    prepost: bool = False,
+    auto_adjust: bool = True,
+    actions: bool = True,
+    session: Client | None = None,
+) -> pl.DataFrame:
+    """Download price history for several tickers concurrently."""
+    ...
+
+
 __all__ = [
     "Ticker",
+    "AsyncTicker",
+    "Tickers",
+    "Client",
+    "download",
     "OptionChain",
+    "YFinanceError",
+    "TickerNotFoundError",
+    "RateLimitError",
+    "NetworkError",
+    "AuthenticationError",
+    "DataParseError",
+    "InvalidParameterError",
     "Period",
     "Interval",
     "DateStr",
+    "DateLike",
+    "GroupBy",
+    "StatementFreq",
+    "StatementLayout",
+    "FundamentalsFreq",
+    "Endpoint",
     "TickerInfo",
     "FastInfo",
     "CalendarInfo",
-    "EarningsInfo",
+    "AnalystPriceTargets",
 ]
 __version__: str
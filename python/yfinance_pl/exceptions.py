@@ -0,0 +1,51 @@
+"""Exception hierarchy for yfinance-pl.
+
+Every error raised while fetching data derives from ``YFinanceError``, so callers can catch that to handle
+any failure, or one of the subclasses to react to a specific one.
+"""
+
+
+class YFinanceError(Exception):
+    """Base class for all yfinance-pl errors."""
+
+
+class TickerNotFoundError(YFinanceError):
+    """Raised when Yahoo Finance has no data for the requested symbol."""
+
+
+class RateLimitError(YFinanceError):
+    """Raised when Yahoo Finance rejects a request with HTTP 429.
+
+    ``retry_after`` holds the suggested wait in seconds, or ``None`` when the server gave no hint.
+    """
+
+    def __init__(self, message: str, retry_after: float | None = None) -> None:
+        super().__init__(message)
+        self.retry_after = retry_after
+
+
+class NetworkError(YFinanceError):
+    """Raised on connection failures, timeouts and unexpected HTTP status codes."""
+
+
+class AuthenticationError(YFinanceError):
+    """Raised when the cookie/crumb handshake with Yahoo Finance fails."""
+
+
+class DataParseError(YFinanceError):
+    """Raised when a Yahoo Finance response cannot be parsed, e.g. after a schema change."""
+
+
+class InvalidParameterError(YFinanceError, ValueError):
+    """Raised when a parameter such as period, interval or a date range is invalid."""
+
+
+__all__ = [
+    "YFinanceError",
+    "TickerNotFoundError",
+    "RateLimitError",
+    "NetworkError",
+    "AuthenticationError",
+    "DataParseError",
+    "InvalidParameterError",
+]
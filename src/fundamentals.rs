@@ -0,0 +1,848 @@
+//! Financial statements and other fundamentals from Yahoo's fundamentals-timeseries endpoint,
+//! keyed by the same line items as yfinance (`TotalRevenue`, `NetIncome`, ...).
+
+use crate::yahoo::Yahoo;
+use crate::{
+    InvalidParameterError, invalid_choice, json_f64, timestamps_to_datetime_series_sec, to_py_err,
+};
+use chrono::{Duration, NaiveDate, Utc};
+use polars::prelude::*;
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use serde_json::Value as Json;
+
+/// Income statement line items, in yfinance's row order
+const INCOME_STMT_KEYS: &[&str] = &[
+    "TaxEffectOfUnusualItems",
+    "TaxRateForCalcs",
+    "NormalizedEBITDA",
+    "NormalizedDilutedEPS",
+    "NormalizedBasicEPS",
+    "TotalUnusualItems",
+    "TotalUnusualItemsExcludingGoodwill",
+    "NetIncomeFromContinuingOperationNetMinorityInterest",
+    "ReconciledDepreciation",
+    "ReconciledCostOfRevenue",
+    "EBITDA",
+    "EBIT",
+    "NetInterestIncome",
+    "InterestExpense",
+    "InterestIncome",
+    "ContinuingAndDiscontinuedDilutedEPS",
+    "ContinuingAndDiscontinuedBasicEPS",
+    "NormalizedIncome",
+    "NetIncomeFromContinuingAndDiscontinuedOperation",
+    "TotalExpenses",
+    "RentExpenseSupplemental",
+    "ReportedNormalizedDilutedEPS",
+    "ReportedNormalizedBasicEPS",
+    "TotalOperatingIncomeAsReported",
+    "DividendPerShare",
+    "DilutedAverageShares",
+    "BasicAverageShares",
+    "DilutedEPS",
+    "DilutedEPSOtherGainsLosses",
+    "TaxLossCarryforwardDilutedEPS",
+    "DilutedAccountingChange",
+    "DilutedExtraordinary",
+    "DilutedDiscontinuousOperations",
+    "DilutedContinuousOperations",
+    "BasicEPS",
+    "BasicEPSOtherGainsLosses",
+    "TaxLossCarryforwardBasicEPS",
+    "BasicAccountingChange",
+    "BasicExtraordinary",
+    "BasicDiscontinuousOperations",
+    "BasicContinuousOperations",
+    "DilutedNIAvailtoComStockholders",
+    "AverageDilutionEarnings",
+    "NetIncomeCommonStockholders",
+    "OtherunderPreferredStockDividend",
+    "PreferredStockDividends",
+    "NetIncome",
+    "MinorityInterests",
+    "NetIncomeIncludingNoncontrollingInterests",
+    "NetIncomeFromTaxLossCarryforward",
+    "NetIncomeExtraordinary",
+    "NetIncomeDiscontinuousOperations",
+    "NetIncomeContinuousOperations",
+    "EarningsFromEquityInterestNetOfTax",
+    "TaxProvision",
+    "PretaxIncome",
+    "OtherIncomeExpense",
+    "OtherNonOperatingIncomeExpenses",
+    "SpecialIncomeCharges",
+    "GainOnSaleOfPPE",
+    "GainOnSaleOfBusiness",
+    "OtherSpecialCharges",
+    "WriteOff",
+    "ImpairmentOfCapitalAssets",
+    "RestructuringAndMergernAcquisition",
+    "SecuritiesAmortization",
+    "EarningsFromEquityInterest",
+    "GainOnSaleOfSecurity",
+    "NetNonOperatingInterestIncomeExpense",
+    "TotalOtherFinanceCost",
+    "InterestExpenseNonOperating",
+    "InterestIncomeNonOperating",
+    "OperatingIncome",
+    "OperatingExpense",
+    "OtherOperatingExpenses",
+    "OtherTaxes",
+    "ProvisionForDoubtfulAccounts",
+    "DepreciationAmortizationDepletionIncomeStatement",
+    "DepletionIncomeStatement",
+    "DepreciationAndAmortizationInIncomeStatement",
+    "Amortization",
+    "AmortizationOfIntangiblesIncomeStatement",
+    "DepreciationIncomeStatement",
+    "ResearchAndDevelopment",
+    "SellingGeneralAndAdministration",
+    "SellingAndMarketingExpense",
+    "GeneralAndAdministrativeExpense",
+    "OtherGandA",
+    "InsuranceAndClaims",
+    "RentAndLandingFees",
+    "SalariesAndWages",
+    "GrossProfit",
+    "CostOfRevenue",
+    "TotalRevenue",
+    "ExciseTaxes",
+    "OperatingRevenue",
+];
+
+/// Balance sheet line items, in yfinance's row order
+const BALANCE_SHEET_KEYS: &[&str] = &[
+    "TreasurySharesNumber",
+    "PreferredSharesNumber",
+    "OrdinarySharesNumber",
+    "ShareIssued",
+    "NetDebt",
+    "TotalDebt",
+    "TangibleBookValue",
+    "InvestedCapital",
+    "WorkingCapital",
+    "NetTangibleAssets",
+    "CapitalLeaseObligations",
+    "CommonStockEquity",
+    "PreferredStockEquity",
+    "TotalCapitalization",
+    "TotalEquityGrossMinorityInterest",
+    "MinorityInterest",
+    "StockholdersEquity",
+    "OtherEquityInterest",
+    "GainsLossesNotAffectingRetainedEarnings",
+    "OtherEquityAdjustments",
+    "FixedAssetsRevaluationReserve",
+    "ForeignCurrencyTranslationAdjustments",
+    "MinimumPensionLiabilities",
+    "UnrealizedGainLoss",
+    "TreasuryStock",
+    "RetainedEarnings",
+    "AdditionalPaidInCapital",
+    "CapitalStock",
+    "OtherCapitalStock",
+    "CommonStock",
+    "PreferredStock",
+    "TotalPartnershipCapital",
+    "GeneralPartnershipCapital",
+    "LimitedPartnershipCapital",
+    "TotalLiabilitiesNetMinorityInterest",
+    "TotalNonCurrentLiabilitiesNetMinorityInterest",
+    "OtherNonCurrentLiabilities",
+    "LiabilitiesHeldforSaleNonCurrent",
+    "RestrictedCommonStock",
+    "PreferredSecuritiesOutsideStockEquity",
+    "DerivativeProductLiabilities",
+    "EmployeeBenefits",
+    "NonCurrentPensionAndOtherPostretirementBenefitPlans",
+    "NonCurrentAccruedExpenses",
+    "DuetoRelatedPartiesNonCurrent",
+    "TradeandOtherPayablesNonCurrent",
+    "NonCurrentDeferredLiabilities",
+    "NonCurrentDeferredRevenue",
+    "NonCurrentDeferredTaxesLiabilities",
+    "LongTermDebtAndCapitalLeaseObligation",
+    "LongTermCapitalLeaseObligation",
+    "LongTermDebt",
+    "LongTermProvisions",
+    "CurrentLiabilities",
+    "OtherCurrentLiabilities",
+    "CurrentDeferredLiabilities",
+    "CurrentDeferredRevenue",
+    "CurrentDeferredTaxesLiabilities",
+    "CurrentDebtAndCapitalLeaseObligation",
+    "CurrentCapitalLeaseObligation",
+    "CurrentDebt",
+    "OtherCurrentBorrowings",
+    "LineOfCredit",
+    "CommercialPaper",
+    "CurrentNotesPayable",
+    "PensionandOtherPostRetirementBenefitPlansCurrent",
+    "CurrentProvisions",
+    "PayablesAndAccruedExpenses",
+    "CurrentAccruedExpenses",
+    "InterestPayable",
+    "Payables",
+    "OtherPayable",
+    "DuetoRelatedPartiesCurrent",
+    "DividendsPayable",
+    "TotalTaxPayable",
+    "IncomeTaxPayable",
+    "AccountsPayable",
+    "TotalAssets",
+    "TotalNonCurrentAssets",
+    "OtherNonCurrentAssets",
+    "DefinedPensionBenefit",
+    "NonCurrentPrepaidAssets",
+    "NonCurrentDeferredAssets",
+    "NonCurrentDeferredTaxesAssets",
+    "DuefromRelatedPartiesNonCurrent",
+    "NonCurrentNoteReceivables",
+    "NonCurrentAccountsReceivable",
+    "FinancialAssets",
+    "InvestmentsAndAdvances",
+    "OtherInvestments",
+    "InvestmentinFinancialAssets",
+    "HeldToMaturitySecurities",
+    "AvailableForSaleSecurities",
+    "FinancialAssetsDesignatedasFairValueThroughProfitorLossTotal",
+    "TradingSecurities",
+    "LongTermEquityInvestment",
+    "InvestmentsinJointVenturesatCost",
+    "InvestmentsInOtherVenturesUnderEquityMethod",
+    "InvestmentsinAssociatesatCost",
+    "InvestmentsinSubsidiariesatCost",
+    "InvestmentProperties",
+    "GoodwillAndOtherIntangibleAssets",
+    "OtherIntangibleAssets",
+    "Goodwill",
+    "NetPPE",
+    "AccumulatedDepreciation",
+    "GrossPPE",
+    "Leases",
+    "ConstructionInProgress",
+    "OtherProperties",
+    "MachineryFurnitureEquipment",
+    "BuildingsAndImprovements",
+    "LandAndImprovements",
+    "Properties",
+    "CurrentAssets",
+    "OtherCurrentAssets",
+    "HedgingAssetsCurrent",
+    "AssetsHeldForSaleCurrent",
+    "CurrentDeferredAssets",
+    "CurrentDeferredTaxesAssets",
+    "RestrictedCash",
+    "PrepaidAssets",
+    "Inventory",
+    "InventoriesAdjustmentsAllowances",
+    "OtherInventories",
+    "FinishedGoods",
+    "WorkInProcess",
+    "RawMaterials",
+    "Receivables",
+    "ReceivablesAdjustmentsAllowances",
+    "OtherReceivables",
+    "DuefromRelatedPartiesCurrent",
+    "TaxesReceivable",
+    "AccruedInterestReceivable",
+    "NotesReceivable",
+    "LoansReceivable",
+    "AccountsReceivable",
+    "AllowanceForDoubtfulAccountsReceivable",
+    "GrossAccountsReceivable",
+    "CashCashEquivalentsAndShortTermInvestments",
+    "OtherShortTermInvestments",
+    "CashAndCashEquivalents",
+    "CashEquivalents",
+    "CashFinancial",
+];
+
+/// Cash flow statement line items, in yfinance's row order
+const CASHFLOW_KEYS: &[&str] = &[
+    "ForeignSales",
+    "DomesticSales",
+    "AdjustedGeographySegmentData",
+    "FreeCashFlow",
+    "RepurchaseOfCapitalStock",
+    "RepaymentOfDebt",
+    "IssuanceOfDebt",
+    "IssuanceOfCapitalStock",
+    "CapitalExpenditure",
+    "InterestPaidSupplementalData",
+    "IncomeTaxPaidSupplementalData",
+    "EndCashPosition",
+    "OtherCashAdjustmentOutsideChangeinCash",
+    "BeginningCashPosition",
+    "EffectOfExchangeRateChanges",
+    "ChangesInCash",
+    "OtherCashAdjustmentInsideChangeinCash",
+    "CashFlowFromDiscontinuedOperation",
+    "FinancingCashFlow",
+    "CashFromDiscontinuedFinancingActivities",
+    "CashFlowFromContinuingFinancingActivities",
+    "NetOtherFinancingCharges",
+    "InterestPaidCFF",
+    "ProceedsFromStockOptionExercised",
+    "CashDividendsPaid",
+    "PreferredStockDividendPaid",
+    "CommonStockDividendPaid",
+    "NetPreferredStockIssuance",
+    "PreferredStockPayments",
+    "PreferredStockIssuance",
+    "NetCommonStockIssuance",
+    "CommonStockPayments",
+    "CommonStockIssuance",
+    "NetIssuancePaymentsOfDebt",
+    "NetShortTermDebtIssuance",
+    "ShortTermDebtPayments",
+    "ShortTermDebtIssuance",
+    "NetLongTermDebtIssuance",
+    "LongTermDebtPayments",
+    "LongTermDebtIssuance",
+    "InvestingCashFlow",
+    "CashFromDiscontinuedInvestingActivities",
+    "CashFlowFromContinuingInvestingActivities",
+    "NetOtherInvestingChanges",
+    "InterestReceivedCFI",
+    "DividendsReceivedCFI",
+    "NetInvestmentPurchaseAndSale",
+    "SaleOfInvestment",
+    "PurchaseOfInvestment",
+    "NetInvestmentPropertiesPurchaseAndSale",
+    "SaleOfInvestmentProperties",
+    "PurchaseOfInvestmentProperties",
+    "NetBusinessPurchaseAndSale",
+    "SaleOfBusiness",
+    "PurchaseOfBusiness",
+    "NetIntangiblesPurchaseAndSale",
+    "SaleOfIntangibles",
+    "PurchaseOfIntangibles",
+    "NetPPEPurchaseAndSale",
+    "SaleOfPPE",
+    "PurchaseOfPPE",
+    "CapitalExpenditureReported",
+    "OperatingCashFlow",
+    "CashFromDiscontinuedOperatingActivities",
+    "CashFlowFromContinuingOperatingActivities",
+    "TaxesRefundPaid",
+    "InterestReceivedCFO",
+    "InterestPaidCFO",
+    "DividendReceivedCFO",
+    "DividendPaidCFO",
+    "ChangeInWorkingCapital",
+    "ChangeInOtherWorkingCapital",
+    "ChangeInOtherCurrentLiabilities",
+    "ChangeInOtherCurrentAssets",
+    "ChangeInPayablesAndAccruedExpense",
+    "ChangeInAccruedExpense",
+    "ChangeInInterestPayable",
+    "ChangeInPayable",
+    "ChangeInDividendPayable",
+    "ChangeInAccountPayable",
+    "ChangeInTaxPayable",
+    "ChangeInIncomeTaxPayable",
+    "ChangeInPrepaidAssets",
+    "ChangeInInventory",
+    "ChangeInReceivables",
+    "ChangesInAccountReceivables",
+    "OtherNonCashItems",
+    "ExcessTaxBenefitFromStockBasedCompensation",
+    "StockBasedCompensation",
+    "UnrealizedGainLossOnInvestmentSecurities",
+    "ProvisionandWriteOffofAssets",
+    "AssetImpairmentCharge",
+    "AmortizationOfSecurities",
+    "DeferredTax",
+    "DeferredIncomeTax",
+    "DepreciationAmortizationDepletion",
+    "Depletion",
+    "DepreciationAndAmortization",
+    "AmortizationCashFlow",
+    "AmortizationOfIntangibles",
+    "Depreciation",
+    "OperatingGainsLosses",
+    "PensionAndEmployeeBenefitExpense",
+    "EarningsLossesFromEquityInvestments",
+    "GainLossOnInvestmentSecurities",
+    "NetForeignCurrencyExchangeGainLoss",
+    "GainLossOnSaleOfPPE",
+    "GainLossOnSaleOfBusiness",
+    "NetIncomeFromContinuingOperations",
+    "CashFlowsfromusedinOperatingActivitiesDirect",
+    "TaxesRefundPaidDirect",
+    "InterestReceivedDirect",
+    "InterestPaidDirect",
+    "DividendsReceivedDirect",
+    "DividendsPaidDirect",
+    "ClassesofCashPayments",
+    "OtherCashPaymentsfromOperatingActivities",
+    "PaymentsonBehalfofEmployees",
+    "PaymentstoSuppliersforGoodsandServices",
+    "ClassesofCashReceiptsfromOperatingActivities",
+    "OtherCashReceiptsfromOperatingActivities",
+    "ReceiptsfromGovernmentGrants",
+    "ReceiptsfromCustomers",
+];
+
+/// First day yfinance requests statements from (2016-12-31)
+const DEFAULT_START: i64 = 1_483_142_400;
+
+/// Types per timeseries request, keeping the query string well under Yahoo's URL limit
+const TYPES_PER_REQUEST: usize = 100;
+
+#[derive(Clone, Copy)]
+pub(crate) enum Statement {
+    Income,
+    BalanceSheet,
+    CashFlow,
+}
+
+impl Statement {
+    const ALL: [Statement; 3] = [
+        Statement::Income,
+        Statement::BalanceSheet,
+        Statement::CashFlow,
+    ];
+
+    fn keys(self) -> &'static [&'static str] {
+        match self {
+            Statement::Income => INCOME_STMT_KEYS,
+            Statement::BalanceSheet => BALANCE_SHEET_KEYS,
+            Statement::CashFlow => CASHFLOW_KEYS,
+        }
+    }
+}
+
+/// Reporting frequency, i.e. the prefix of the timeseries type (`annualTotalRevenue`)
+#[derive(Clone, Copy)]
+pub(crate) enum Freq {
+    Yearly,
+    Quarterly,
+    /// Trailing twelve months, as reported by Yahoo (income and cash flow items only)
+    Trailing,
+}
+
+impl Freq {
+    const NAMES: &'static [&'static str] = &["yearly", "annual", "quarterly", "trailing"];
+
+    pub(crate) fn parse(freq: &str) -> PyResult<Self> {
+        match freq {
+            "yearly" | "annual" => Ok(Freq::Yearly),
+            "quarterly" => Ok(Freq::Quarterly),
+            "trailing" => Ok(Freq::Trailing),
+            _ => Err(invalid_choice("freq", freq, Self::NAMES)),
+        }
+    }
+
+    fn prefix(self) -> &'static str {
+        match self {
+            Freq::Yearly => "annual",
+            Freq::Quarterly => "quarterly",
+            Freq::Trailing => "trailing",
+        }
+    }
+}
+
+/// Shape of a statement frame
+#[derive(Clone, Copy)]
+pub(crate) enum Layout {
+    /// One row per (date, line item)
+    Long,
+    /// One row per report date, one column per line item
+    Wide,
+    /// One row per line item, one column per report date (newest first), as in yfinance
+    Yfinance,
+}
+
+impl Layout {
+    const NAMES: &'static [&'static str] = &["long", "wide", "yfinance"];
+
+    pub(crate) fn parse(layout: &str) -> PyResult<Self> {
+        match layout {
+            "long" => Ok(Layout::Long),
+            "wide" => Ok(Layout::Wide),
+            "yfinance" => Ok(Layout::Yfinance),
+            _ => Err(invalid_choice("layout", layout, Self::NAMES)),
+        }
+    }
+}
+
+/// One reported value of a line item
+pub(crate) struct Point {
+    pub key: String,
+    pub date: NaiveDate,
+    pub value: f64,
+    pub currency: Option<String>,
+}
+
+/// Fetch `keys` at `freq` reported between `start` and `end` (Unix seconds, defaulting to
+/// yfinance's window), skipping periods Yahoo left empty
+pub(crate) async fn fetch_points<K: AsRef<str>>(
+    yahoo: &Yahoo,
+    symbol: &str,
+    keys: &[K],
+    freq: Freq,
+    start: Option<i64>,
+    end: Option<i64>,
+) -> PyResult<Vec<Point>> {
+    let prefix = freq.prefix();
+    let types: Vec<String> = keys
+        .iter()
+        .map(|key| format!("{}{}", prefix, key.as_ref()))
+        .collect();
+    let (period1, period2) = (
+        start.unwrap_or(DEFAULT_START),
+        end.unwrap_or_else(|| Utc::now().timestamp()),
+    );
+    let batches = futures::future::try_join_all(
+        types
+            .chunks(TYPES_PER_REQUEST)
+            .map(|chunk| yahoo.timeseries(symbol, chunk, period1, period2)),
+    )
+    .await?;
+
+    let mut points = Vec::new();
+    for result in batches.iter().flatten() {
+        let Some(name) = result
+            .get("meta")
+            .and_then(|m| m.pointer("/type/0"))
+            .and_then(Json::as_str)
+        else {
+            continue;
+        };
+        let Some(key) = name
+            .strip_prefix(prefix)
+            .filter(|key| keys.iter().any(|k| k.as_ref() == *key))
+        else {
+            continue;
+        };
+        let Some(Json::Array(values)) = result.get(name) else {
+            continue;
+        };
+        for value in values {
+            let date = value
+                .get("asOfDate")
+                .and_then(Json::as_str)
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+            let amount = json_f64(value.get("reportedValue"));
+            if let (Some(date), Some(amount)) = (date, amount) {
+                points.push(Point {
+                    key: key.to_string(),
+                    date,
+                    value: amount,
+                    currency: value
+                        .get("currencyCode")
+                        .and_then(Json::as_str)
+                        .map(str::to_string),
+                });
+            }
+        }
+    }
+
+    Ok(points)
+}
+
+/// yfinance's display name for a line item key, e.g. `NetPPE` -> `Net PPE`
+pub(crate) fn line_item_name(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    let mut name = String::with_capacity(key.len() + 8);
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_ascii_uppercase() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(char::is_ascii_lowercase);
+            if prev.is_ascii_lowercase() || (prev.is_ascii_uppercase() && next_lower) {
+                name.push(' ');
+            }
+        }
+        name.push(c);
+    }
+    name
+}
+
+/// Arrange statement values into `layout`, with line items in `keys` order and dates newest first
+fn statement_frame(points: &[Point], keys: &[&str], layout: Layout) -> PolarsResult<DataFrame> {
+    let mut dates: Vec<NaiveDate> = points.iter().map(|p| p.date).collect();
+    dates.sort_unstable_by(|a, b| b.cmp(a));
+    dates.dedup();
+    let items: Vec<&str> = keys
+        .iter()
+        .copied()
+        .filter(|key| points.iter().any(|p| p.key == *key))
+        .collect();
+    let value = |key: &str, date: NaiveDate| {
+        points
+            .iter()
+            .find(|p| p.key == key && p.date == date)
+            .map(|p| p.value)
+    };
+
+    match layout {
+        Layout::Long => {
+            let (mut date_col, mut item_col, mut value_col) = (Vec::new(), Vec::new(), Vec::new());
+            for &date in &dates {
+                for &key in &items {
+                    if let Some(v) = value(key, date) {
+                        date_col.push(date);
+                        item_col.push(line_item_name(key));
+                        value_col.push(v);
+                    }
+                }
+            }
+            DataFrame::new(vec![
+                Column::new("date".into(), date_col),
+                Column::new("line_item".into(), item_col),
+                Column::new("value".into(), value_col),
+            ])
+        }
+        Layout::Wide => {
+            let mut columns = vec![Column::new("date".into(), &dates)];
+            for &key in &items {
+                let values: Vec<Option<f64>> = dates.iter().map(|&d| value(key, d)).collect();
+                columns.push(Column::new(line_item_name(key).into(), values));
+            }
+            DataFrame::new(columns)
+        }
+        Layout::Yfinance => {
+            let names: Vec<String> = items.iter().map(|key| line_item_name(key)).collect();
+            let mut columns = vec![Column::new("line_item".into(), names)];
+            for &date in &dates {
+                let values: Vec<Option<f64>> = items.iter().map(|key| value(key, date)).collect();
+                columns.push(Column::new(date.to_string().into(), values));
+            }
+            DataFrame::new(columns)
+        }
+    }
+}
+
+/// Fetch a full statement at `freq` and arrange it into `layout`
+pub(crate) async fn fetch_statement(
+    yahoo: Yahoo,
+    symbol: String,
+    statement: Statement,
+    freq: Freq,
+    layout: Layout,
+) -> PyResult<PyDataFrame> {
+    // Balances are point-in-time, so Yahoo has no trailing series for them
+    if matches!((statement, freq), (Statement::BalanceSheet, Freq::Trailing)) {
+        return Err(InvalidParameterError::new_err(
+            "freq='trailing' is not available for the balance sheet",
+        ));
+    }
+    let keys = statement.keys();
+    let points = fetch_points(&yahoo, &symbol, keys, freq, None, None).await?;
+    let df = statement_frame(&points, keys, layout).map_err(to_py_err)?;
+    Ok(PyDataFrame(df))
+}
+
+/// Every line item of the income statement, balance sheet and cash flow statement
+fn all_keys() -> Vec<&'static str> {
+    Statement::ALL
+        .iter()
+        .flat_map(|statement| statement.keys().iter().copied())
+        .collect()
+}
+
+/// Long frame (date, key, value, currency) of arbitrary timeseries keys, oldest first per key;
+/// `keys` defaults to every statement line item
+pub(crate) async fn fetch_fundamentals(
+    yahoo: Yahoo,
+    symbol: String,
+    keys: Option<Vec<String>>,
+    freq: Freq,
+    start: Option<i64>,
+    end: Option<i64>,
+) -> PyResult<PyDataFrame> {
+    let keys: Vec<String> = match keys {
+        Some(keys) => keys,
+        None => all_keys().into_iter().map(str::to_string).collect(),
+    };
+    let mut points = fetch_points(&yahoo, &symbol, &keys, freq, start, end).await?;
+    points.sort_by(|a, b| {
+        let rank = |p: &Point| keys.iter().position(|k| *k == p.key);
+        rank(a).cmp(&rank(b)).then(a.date.cmp(&b.date))
+    });
+
+    let dates: Vec<NaiveDate> = points.iter().map(|p| p.date).collect();
+    let names: Vec<&str> = points.iter().map(|p| p.key.as_str()).collect();
+    let values: Vec<f64> = points.iter().map(|p| p.value).collect();
+    let currencies: Vec<Option<&str>> = points.iter().map(|p| p.currency.as_deref()).collect();
+    let df = DataFrame::new(vec![
+        Column::new("date".into(), dates),
+        Column::new("key".into(), names),
+        Column::new("value".into(), values),
+        Column::new("currency".into(), currencies),
+    ])
+    .map_err(to_py_err)?;
+
+    Ok(PyDataFrame(df))
+}
+
+/// Default `get_shares_full` window when no start is given, as in yfinance
+const SHARES_LOOKBACK_DAYS: i64 = 548;
+
+/// Shares outstanding history between `start` and `end` (Unix seconds, defaulting to the last
+/// 548 days), one row per distinct timestamp in ascending order
+pub(crate) async fn fetch_shares_full(
+    yahoo: Yahoo,
+    symbol: String,
+    start: Option<i64>,
+    end: Option<i64>,
+) -> PyResult<PyDataFrame> {
+    let period2 = end.unwrap_or_else(|| Utc::now().timestamp());
+    let period1 = start.unwrap_or(period2 - Duration::days(SHARES_LOOKBACK_DAYS).num_seconds());
+    let results = yahoo
+        .timeseries(&symbol, &["sharesOut".to_string()], period1, period2)
+        .await?;
+
+    let mut rows: Vec<(i64, i64)> = Vec::new();
+    for result in &results {
+        let (Some(Json::Array(timestamps)), Some(Json::Array(shares))) =
+            (result.get("timestamp"), result.get("shares_out"))
+        else {
+            continue;
+        };
+        rows.extend(timestamps.iter().zip(shares).filter_map(|(ts, shares)| {
+            let shares = shares
+                .as_i64()
+                .or_else(|| shares.as_f64().map(|v| v.round() as i64))?;
+            Some((ts.as_i64()?, shares))
+        }));
+    }
+    // Yahoo can repeat a timestamp; keep its last reported count
+    rows.sort_by_key(|&(ts, _)| ts);
+    rows.reverse();
+    rows.dedup_by_key(|&mut (ts, _)| ts);
+    rows.reverse();
+
+    let timestamps: Vec<i64> = rows.iter().map(|&(ts, _)| ts).collect();
+    let shares: Vec<i64> = rows.iter().map(|&(_, shares)| shares).collect();
+    let df = DataFrame::new(vec![
+        timestamps_to_datetime_series_sec(&timestamps, "date").into(),
+        Column::new("shares".into(), shares),
+    ])
+    .map_err(to_py_err)?;
+
+    Ok(PyDataFrame(df))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(key: &str, date: &str, value: f64) -> Point {
+        Point {
+            key: key.to_string(),
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            value,
+            currency: Some("USD".to_string()),
+        }
+    }
+
+    /// Two years of revenue, one year of net income and no gross profit
+    fn points() -> Vec<Point> {
+        vec![
+            point("TotalRevenue", "2022-12-31", 90.0),
+            point("NetIncome", "2023-12-31", 10.0),
+            point("TotalRevenue", "2023-12-31", 100.0),
+        ]
+    }
+
+    const KEYS: &[&str] = &["TotalRevenue", "GrossProfit", "NetIncome"];
+
+    fn names(df: &DataFrame) -> Vec<&str> {
+        df.get_column_names().iter().map(|n| n.as_str()).collect()
+    }
+
+    fn floats(df: &DataFrame, name: &str) -> Vec<Option<f64>> {
+        df.column(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    fn strings(df: &DataFrame, name: &str) -> Vec<String> {
+        let column = df.column(name).unwrap().cast(&DataType::String).unwrap();
+        let values = column.str().unwrap().into_iter();
+        values.map(|v| v.unwrap_or_default().to_string()).collect()
+    }
+
+    #[test]
+    fn freq_accepts_annual_as_yearly() {
+        for name in ["yearly", "annual"] {
+            assert!(matches!(Freq::parse(name), Ok(Freq::Yearly)), "{name}");
+            assert_eq!(Freq::parse(name).unwrap().prefix(), "annual");
+        }
+        assert!(matches!(Freq::parse("quarterly"), Ok(Freq::Quarterly)));
+        assert!(matches!(Freq::parse("trailing"), Ok(Freq::Trailing)));
+        assert!(Freq::parse("monthly").is_err());
+    }
+
+    #[test]
+    fn line_item_names_match_yfinance() {
+        let cases = [
+            ("TotalRevenue", "Total Revenue"),
+            ("NetPPE", "Net PPE"),
+            ("EBITDA", "EBITDA"),
+            ("NormalizedEBITDA", "Normalized EBITDA"),
+            ("DilutedEPS", "Diluted EPS"),
+            ("TaxRateForCalcs", "Tax Rate For Calcs"),
+            (
+                "NetIncomeFromContinuingOperationNetMinorityInterest",
+                "Net Income From Continuing Operation Net Minority Interest",
+            ),
+        ];
+        for (key, name) in cases {
+            assert_eq!(line_item_name(key), name);
+        }
+    }
+
+    #[test]
+    fn long_statement_has_one_row_per_reported_value() {
+        let df = statement_frame(&points(), KEYS, Layout::Long).unwrap();
+        assert_eq!(names(&df), ["date", "line_item", "value"]);
+        assert_eq!(
+            strings(&df, "date"),
+            ["2023-12-31", "2023-12-31", "2022-12-31"]
+        );
+        assert_eq!(
+            strings(&df, "line_item"),
+            ["Total Revenue", "Net Income", "Total Revenue"]
+        );
+        assert_eq!(floats(&df, "value"), [Some(100.0), Some(10.0), Some(90.0)]);
+    }
+
+    #[test]
+    fn wide_statement_has_one_row_per_date() {
+        let df = statement_frame(&points(), KEYS, Layout::Wide).unwrap();
+        assert_eq!(names(&df), ["date", "Total Revenue", "Net Income"]);
+        assert_eq!(strings(&df, "date"), ["2023-12-31", "2022-12-31"]);
+        assert_eq!(floats(&df, "Total Revenue"), [Some(100.0), Some(90.0)]);
+        assert_eq!(floats(&df, "Net Income"), [Some(10.0), None]);
+    }
+
+    #[test]
+    fn yfinance_statement_has_one_column_per_date() {
+        let df = statement_frame(&points(), KEYS, Layout::Yfinance).unwrap();
+        assert_eq!(names(&df), ["line_item", "2023-12-31", "2022-12-31"]);
+        assert_eq!(strings(&df, "line_item"), ["Total Revenue", "Net Income"]);
+        assert_eq!(floats(&df, "2023-12-31"), [Some(100.0), Some(10.0)]);
+        assert_eq!(floats(&df, "2022-12-31"), [Some(90.0), None]);
+    }
+
+    #[test]
+    fn empty_statement_keeps_its_columns() {
+        for (layout, columns) in [
+            (Layout::Long, &["date", "line_item", "value"][..]),
+            (Layout::Wide, &["date"][..]),
+            (Layout::Yfinance, &["line_item"][..]),
+        ] {
+            let df = statement_frame(&[], KEYS, layout).unwrap();
+            assert_eq!(names(&df), columns);
+            assert_eq!(df.height(), 0);
+        }
+    }
+}
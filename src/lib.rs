@@ -1,737 +1,4491 @@
+use chrono::TimeZone as _;
+use chrono::{
+    DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc,
+};
+use chrono_tz::Tz;
+use futures::stream::{self, StreamExt};
 use paft::prelude::ToDataFrameVec;
+use polars::functions::concat_df_diagonal;
 use polars::prelude::*;
+use pyo3::exceptions::{PyTypeError, PyUserWarning};
+use pyo3::import_exception;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDate, PyDateTime, PyDict};
 use pyo3_polars::PyDataFrame;
-use yfinance_rs::core::{Interval, Range};
-use yfinance_rs::{Ticker as YfTicker, YfClient};
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::sync::OnceLock;
+use std::time::Duration;
+use url::Url;
+use yfinance_rs::core::client::Backoff;
+use yfinance_rs::core::{Action, Interval, Range};
+use yfinance_rs::{Ticker as YfTicker, YfClient, YfError};
 
-fn create_runtime() -> PyResult<tokio::runtime::Runtime> {
-    tokio::runtime::Runtime::new()
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+mod fundamentals;
+mod ratios;
+mod yahoo;
+
+use fundamentals::{Freq, Layout, Statement};
+use yahoo::{DEFAULT_CONNECT_TIMEOUT, DEFAULT_TIMEOUT, Yahoo, YahooConfig};
+
+// Defined in python/yfinance_pl/exceptions.py (InvalidParameterError also subclasses ValueError)
+import_exception!(yfinance_pl.exceptions, YFinanceError);
+import_exception!(yfinance_pl.exceptions, TickerNotFoundError);
+import_exception!(yfinance_pl.exceptions, RateLimitError);
+import_exception!(yfinance_pl.exceptions, NetworkError);
+import_exception!(yfinance_pl.exceptions, AuthenticationError);
+import_exception!(yfinance_pl.exceptions, DataParseError);
+import_exception!(yfinance_pl.exceptions, InvalidParameterError);
+
+static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+static DEFAULT_CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// Process-wide Tokio runtime, created on first use
+fn runtime() -> PyResult<&'static tokio::runtime::Runtime> {
+    if let Some(rt) = RUNTIME.get() {
+        return Ok(rt);
+    }
+    let rt = tokio::runtime::Runtime::new()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    let rt = RUNTIME.get_or_init(|| rt);
+    // Share the runtime with pyo3-async-runtimes so AsyncTicker futures run on it too
+    let _ = pyo3_async_runtimes::tokio::init_with_runtime(rt);
+    Ok(rt)
+}
+
+/// Run a future on the shared runtime with the GIL released.
+///
+/// Python objects must be built from the result after this returns.
+fn block_on<F, T>(py: Python<'_>, fut: F) -> PyResult<T>
+where
+    F: Future<Output = PyResult<T>> + Send,
+    T: Send,
+{
+    let rt = runtime()?;
+    py.allow_threads(|| rt.block_on(fut))
+}
+
+/// Wrap a future as a Python awaitable driven by the shared runtime
+fn awaitable<'py, F, T>(py: Python<'py>, fut: F) -> PyResult<Bound<'py, PyAny>>
+where
+    F: Future<Output = PyResult<T>> + Send + 'static,
+    T: for<'a> IntoPyObject<'a> + Send + 'static,
+{
+    runtime()?;
+    pyo3_async_runtimes::tokio::future_into_py(py, fut)
+}
+
+/// Process-wide default client, shared so the cookie/crumb and connection pool are reused
+fn default_client() -> PyResult<Client> {
+    if let Some(client) = DEFAULT_CLIENT.get() {
+        return Ok(client.clone());
+    }
+    let client = Client::new(None, None, None, None, None, None, None, None)?;
+    Ok(DEFAULT_CLIENT.get_or_init(|| client).clone())
+}
+
+fn to_py_err<E: std::fmt::Display>(e: E) -> PyErr {
+    YFinanceError::new_err(e.to_string())
+}
+
+/// Raised when a response cannot be shaped into the expected data
+fn parse_err<E: std::fmt::Display>(e: E) -> PyErr {
+    DataParseError::new_err(e.to_string())
+}
+
+/// Yahoo reports unknown or delisted symbols as API errors as well as 404s
+fn is_not_found(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("not found") || message.contains("delisted")
+}
+
+/// Map a yfinance-rs error onto the matching `yfinance_pl.exceptions` class
+fn yf_err(e: YfError) -> PyErr {
+    let message = e.to_string();
+    match e {
+        YfError::NotFound { .. } => TickerNotFoundError::new_err(message),
+        YfError::Api(ref msg) if is_not_found(msg) => TickerNotFoundError::new_err(message),
+        // yfinance-rs keeps the response headers to itself, so Retry-After (which the raw client in
+        // yahoo.rs does pass on) is not available for these requests
+        YfError::RateLimited { .. } => RateLimitError::new_err((message, None::<f64>)),
+        YfError::Auth(_)
+        | YfError::Status {
+            status: 401 | 403, ..
+        } => AuthenticationError::new_err(message),
+        YfError::Http(_) | YfError::ServerError { .. } | YfError::Status { .. } => {
+            NetworkError::new_err(message)
+        }
+        YfError::Json(_) | YfError::MissingData(_) => DataParseError::new_err(message),
+        YfError::InvalidParams(_) | YfError::InvalidDates => {
+            InvalidParameterError::new_err(message)
+        }
+        _ => YFinanceError::new_err(message),
+    }
+}
+
+/// Convert 'ts' column (i64 milliseconds) to 'date' column (Datetime type)
+fn convert_ts_to_date(df: DataFrame) -> PolarsResult<DataFrame> {
+    if let Ok(ts_col) = df.column("ts") {
+        // Cast ts column to Datetime
+        let date_col = ts_col
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+            .with_name("date".into());
+
+        // Drop ts column and add date column
+        let mut df = df.drop("ts")?;
+        df.with_column(date_col)?;
+        Ok(df)
+    } else {
+        Ok(df)
+    }
+}
+
+/// Convert i64 timestamps (seconds) to a Datetime Series
+fn timestamps_to_datetime_series_sec(timestamps: &[i64], name: &str) -> Series {
+    // Convert seconds to milliseconds
+    let timestamps_ms: Vec<i64> = timestamps.iter().map(|ts| ts * 1000).collect();
+    Series::new(name.into(), timestamps_ms)
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+        .unwrap()
+}
+
+const VALID_PERIODS: &[&str] = &[
+    "1d", "5d", "1mo", "3mo", "6mo", "1y", "2y", "5y", "10y", "ytd", "max",
+];
+
+const VALID_INTERVALS: &[&str] = &[
+    "1m", "2m", "5m", "15m", "30m", "60m", "90m", "1h", "1d", "5d", "1wk", "1mo", "3mo",
+];
+
+fn invalid_choice(name: &str, value: &str, valid: &[&str]) -> PyErr {
+    InvalidParameterError::new_err(format!(
+        "Invalid {} '{}'. Valid values: {}",
+        name,
+        value,
+        valid.join(", ")
+    ))
+}
+
+fn parse_range(period: &str) -> PyResult<Range> {
+    match period {
+        "1d" => Ok(Range::D1),
+        "5d" => Ok(Range::D5),
+        "1mo" => Ok(Range::M1),
+        "3mo" => Ok(Range::M3),
+        "6mo" => Ok(Range::M6),
+        "1y" => Ok(Range::Y1),
+        "2y" => Ok(Range::Y2),
+        "5y" => Ok(Range::Y5),
+        "10y" => Ok(Range::Y10),
+        "ytd" => Ok(Range::Ytd),
+        "max" => Ok(Range::Max),
+        _ => Err(invalid_choice("period", period, VALID_PERIODS)),
+    }
+}
+
+fn parse_interval(interval: &str) -> PyResult<Interval> {
+    match interval {
+        "1m" => Ok(Interval::I1m),
+        "2m" => Ok(Interval::I2m),
+        "5m" => Ok(Interval::I5m),
+        "15m" => Ok(Interval::I15m),
+        "30m" => Ok(Interval::I30m),
+        "90m" => Ok(Interval::I90m),
+        "60m" | "1h" => Ok(Interval::I1h),
+        "1d" => Ok(Interval::D1),
+        "5d" => Ok(Interval::D5),
+        "1wk" => Ok(Interval::W1),
+        "1mo" => Ok(Interval::M1),
+        "3mo" => Ok(Interval::M3),
+        _ => Err(invalid_choice("interval", interval, VALID_INTERVALS)),
+    }
+}
+
+/// Yahoo's limits for intraday intervals: (maximum lookback in days, maximum span per request in days)
+fn interval_limits(interval: &str) -> Option<(i64, Option<i64>)> {
+    match interval {
+        "1m" => Some((30, Some(7))),
+        "2m" | "5m" | "15m" | "30m" | "90m" => Some((60, None)),
+        "60m" | "1h" => Some((730, None)),
+        _ => None,
+    }
+}
+
+/// Period used when none is given: yfinance's 1mo, shortened to 5d for intervals whose request
+/// span Yahoo caps below a month (1m)
+fn default_period(interval: &str) -> &'static str {
+    match interval_limits(interval) {
+        Some((_, Some(span))) if span < 30 => "5d",
+        _ => "1mo",
+    }
+}
+
+/// Reject windows Yahoo will not serve for the given interval, before making a request
+fn validate_interval_window(
+    interval: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> PyResult<()> {
+    let Some((lookback, max_span)) = interval_limits(interval) else {
+        return Ok(());
+    };
+
+    if start < now - TimeDelta::days(lookback) {
+        return Err(InvalidParameterError::new_err(format!(
+            "{} data is only available for the last {} days (requested start: {})",
+            interval, lookback, start
+        )));
+    }
+    if let Some(span) = max_span.filter(|&span| end - start > TimeDelta::days(span)) {
+        return Err(InvalidParameterError::new_err(format!(
+            "{} data can be requested for at most {} days at a time",
+            interval, span
+        )));
+    }
+    Ok(())
+}
+
+/// Date or datetime accepted by `start`/`end` (str, `datetime.date` or `datetime.datetime`)
+enum DateArg {
+    Aware(DateTime<Utc>),
+    Naive(NaiveDateTime),
+    Date(NaiveDate),
+    Str(String),
+}
+
+impl<'py> FromPyObject<'py> for DateArg {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = ob.py();
+        // datetime subclasses date, so it must be matched first to keep its time of day
+        if let Ok(dt) = ob.downcast::<PyDateTime>() {
+            // utcoffset() is given the datetime itself, which zoneinfo, pytz and pandas zones need
+            // (pyo3's FixedOffset conversion passes None and gets no offset back for them)
+            let aware = !dt.call_method0("utcoffset")?.is_none();
+            let dt = if aware {
+                let utc = py.import("datetime")?.getattr("timezone")?.getattr("utc")?;
+                dt.call_method1("astimezone", (utc,))?
+            } else {
+                dt.clone().into_any()
+            };
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("tzinfo", py.None())?;
+            let naive: NaiveDateTime = dt.call_method("replace", (), Some(&kwargs))?.extract()?;
+            return Ok(if aware {
+                DateArg::Aware(naive.and_utc())
+            } else {
+                DateArg::Naive(naive)
+            });
+        }
+        if let Ok(date) = ob.downcast::<PyDate>() {
+            return Ok(DateArg::Date(date.extract()?));
+        }
+        if let Ok(s) = ob.extract::<String>() {
+            return Ok(DateArg::Str(s));
+        }
+        Err(PyTypeError::new_err(format!(
+            "expected a str, date or datetime, got {}",
+            ob.get_type().name()?
+        )))
+    }
+}
+
+impl DateArg {
+    /// An instant for zone-aware values, or a local wall-clock time (midnight for plain dates)
+    fn to_bound(&self) -> PyResult<DateBound> {
+        match self {
+            DateArg::Aware(dt) => Ok(DateBound::Instant(*dt)),
+            DateArg::Naive(dt) => Ok(DateBound::Local(*dt)),
+            DateArg::Date(d) => Ok(DateBound::Local(d.and_time(NaiveTime::MIN))),
+            DateArg::Str(s) => parse_date_str(s),
+        }
+    }
+
+    /// Convert to a UTC timestamp (naive values and plain dates are treated as UTC)
+    fn to_utc(&self) -> PyResult<DateTime<Utc>> {
+        Ok(self.to_bound()?.resolve(Tz::UTC))
+    }
+}
+
+/// A parsed `start`/`end`: plain dates and naive datetimes are kept as wall-clock times until
+/// the timezone they are read in (the exchange's, for history) is known
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DateBound {
+    Instant(DateTime<Utc>),
+    Local(NaiveDateTime),
+}
+
+impl DateBound {
+    /// The instant this bound refers to, reading a wall-clock time in `tz`
+    fn resolve(self, tz: Tz) -> DateTime<Utc> {
+        match self {
+            DateBound::Instant(dt) => dt,
+            DateBound::Local(local) => {
+                // A time inside a DST gap (e.g. a midnight that was skipped) moves an hour later
+                [local, local + TimeDelta::hours(1)]
+                    .into_iter()
+                    .find_map(|local| tz.from_local_datetime(&local).earliest())
+                    .map_or_else(|| local.and_utc(), |dt| dt.with_timezone(&Utc))
+            }
+        }
+    }
+}
+
+/// `start`/`end` as Unix seconds, rejecting an empty range
+fn timestamp_range(
+    start: Option<DateArg>,
+    end: Option<DateArg>,
+) -> PyResult<(Option<i64>, Option<i64>)> {
+    let start = start.map(|d| d.to_utc()).transpose()?;
+    let end = end.map(|d| d.to_utc()).transpose()?;
+    match (start, end) {
+        (Some(start), Some(end)) if start >= end => Err(InvalidParameterError::new_err(format!(
+            "start ({}) must be earlier than end ({})",
+            start, end
+        ))),
+        _ => Ok((start.map(|dt| dt.timestamp()), end.map(|dt| dt.timestamp()))),
+    }
+}
+
+fn parse_date_str(s: &str) -> PyResult<DateBound> {
+    let s = s.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(DateBound::Instant(dt.with_timezone(&Utc)));
+    }
+    for fmt in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Ok(DateBound::Local(dt));
+        }
+    }
+    if let Ok(d) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(DateBound::Local(d.and_time(NaiveTime::MIN)));
+    }
+    Err(InvalidParameterError::new_err(format!(
+        "Invalid date '{}': expected YYYY-MM-DD, YYYY-MM-DD HH:MM:SS or an ISO 8601 datetime",
+        s
+    )))
+}
+
+/// Move `dt` by the length of a fixed-size period (backwards when `forward` is false).
+/// Returns None for periods without a fixed length (ytd, max).
+fn shift_by_period(dt: DateTime<Utc>, period: &str, forward: bool) -> Option<DateTime<Utc>> {
+    let (days, months) = match period {
+        "1d" => (1, 0),
+        "5d" => (5, 0),
+        "1mo" => (0, 1),
+        "3mo" => (0, 3),
+        "6mo" => (0, 6),
+        "1y" => (0, 12),
+        "2y" => (0, 24),
+        "5y" => (0, 60),
+        "10y" => (0, 120),
+        _ => return None,
+    };
+    if forward {
+        dt.checked_add_days(Days::new(days))?
+            .checked_add_months(Months::new(months))
+    } else {
+        dt.checked_sub_days(Days::new(days))?
+            .checked_sub_months(Months::new(months))
+    }
+}
+
+/// Start of the window Yahoo serves for a `period` ending at `now`
+fn period_start(period: &str, now: DateTime<Utc>) -> DateTime<Utc> {
+    match period {
+        "ytd" => NaiveDate::from_ymd_opt(now.year(), 1, 1)
+            .map(|d| d.and_time(NaiveTime::MIN).and_utc())
+            .unwrap_or(now),
+        "max" => DateTime::<Utc>::MIN_UTC,
+        _ => shift_by_period(now, period, false).unwrap_or(now),
+    }
+}
+
+/// Resolve `period`/`start`/`end` into an explicit `[start, end)` window.
+///
+/// Returns None when only `period` (or nothing) is given, in which case the range is used as-is.
+/// A fixed-length `period` may be combined with exactly one of `start`/`end` to anchor the window;
+/// a missing `end` defaults to now and a missing `start` to one month before `end`.
+fn resolve_history_window(
+    period: Option<&str>,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+) -> PyResult<Option<(DateTime<Utc>, DateTime<Utc>)>> {
+    let incompatible = |p: &str| {
+        InvalidParameterError::new_err(format!(
+            "period '{}' cannot be combined with start/end; use a fixed-length period or drop it",
+            p
+        ))
+    };
+
+    let (start, end) = match (period, start, end) {
+        (_, None, None) => return Ok(None),
+        (Some(_), Some(_), Some(_)) => {
+            return Err(InvalidParameterError::new_err(
+                "period cannot be combined with both start and end",
+            ));
+        }
+        (Some(p), Some(s), None) => (
+            s,
+            shift_by_period(s, p, true).ok_or_else(|| incompatible(p))?,
+        ),
+        (Some(p), None, Some(e)) => (
+            shift_by_period(e, p, false).ok_or_else(|| incompatible(p))?,
+            e,
+        ),
+        (None, Some(s), None) => (s, Utc::now()),
+        (None, None, Some(e)) => (shift_by_period(e, "1mo", false).unwrap_or(e), e),
+        (None, Some(s), Some(e)) => (s, e),
+    };
+
+    if start >= end {
+        return Err(InvalidParameterError::new_err(format!(
+            "start ({}) must be earlier than end ({})",
+            start, end
+        )));
+    }
+    Ok(Some((start, end)))
+}
+
+/// Convert a timeout/backoff given in seconds into a Duration
+fn seconds(name: &str, value: f64) -> PyResult<Duration> {
+    Duration::try_from_secs_f64(value).map_err(|_| {
+        InvalidParameterError::new_err(format!(
+            "{} must be a non-negative number of seconds, got {}",
+            name, value
+        ))
+    })
+}
+
+/// Endpoints that can be overridden, with their default path relative to Yahoo's hosts.
+/// The paths are used to derive every endpoint from a single `base_url` (e.g. a local mock server).
+const ENDPOINTS: &[(&str, &str)] = &[
+    ("chart", "v8/finance/chart/"),
+    ("quote_summary", "v10/finance/quoteSummary/"),
+    ("quote", "v7/finance/quote"),
+    ("options", "v7/finance/options/"),
+    (
+        "timeseries",
+        "ws/fundamentals-timeseries/v1/finance/timeseries/",
+    ),
+    ("visualization", "v1/finance/visualization"),
+    ("cookie", "consent"),
+    ("crumb", "v1/test/getcrumb"),
+];
+
+fn parse_url(name: &str, value: &str) -> PyResult<Url> {
+    Url::parse(value).map_err(|e| {
+        InvalidParameterError::new_err(format!("Invalid URL for {} '{}': {}", name, value, e))
+    })
+}
+
+/// Resolve endpoint overrides: explicit `base_urls` entries win over URLs derived from `base_url`
+fn resolve_endpoints(
+    base_url: Option<&str>,
+    base_urls: Option<HashMap<String, String>>,
+) -> PyResult<Vec<(&'static str, Url)>> {
+    let names: Vec<&str> = ENDPOINTS.iter().map(|(name, _)| *name).collect();
+    let mut overrides = base_urls.unwrap_or_default();
+    if let Some(unknown) = overrides.keys().find(|k| !names.contains(&k.as_str())) {
+        return Err(invalid_choice("base_urls key", unknown, &names));
+    }
+
+    let base = base_url
+        .map(|b| parse_url("base_url", &format!("{}/", b.trim_end_matches('/'))))
+        .transpose()?;
+
+    let mut resolved = Vec::new();
+    for (name, path) in ENDPOINTS {
+        let url = match (overrides.remove(*name), &base) {
+            (Some(explicit), _) => parse_url(name, &explicit)?,
+            (None, Some(base)) => base.join(path).map_err(to_py_err)?,
+            (None, None) => continue,
+        };
+        resolved.push((*name, url));
+    }
+    Ok(resolved)
+}
+
+/// HTTP client configuration shared by Ticker, AsyncTicker, Tickers and download()
+///
+/// Args:
+///     proxy: Proxy URL for all requests (e.g. "http://proxy.local:8080")
+///     timeout: Total request timeout in seconds (default 30)
+///     connect_timeout: Connection timeout in seconds (default 10)
+///     retries: Maximum number of retries for failed requests (0 disables retrying)
+///     backoff: Base delay in seconds for exponential backoff between retries
+///     user_agent: Custom User-Agent header
+///     base_url: Serve every endpoint from this host, keeping Yahoo's paths
+///         (e.g. "http://127.0.0.1:8080" for a local mock server)
+///     base_urls: Per-endpoint overrides keyed by chart, quote_summary, quote, options, timeseries,
+///         visualization, cookie or crumb; these take precedence over base_url
+///
+/// Price history, options and the other typed endpoints go through yfinance-rs. quoteSummary
+/// modules, quotes, fundamentals timeseries and earnings dates are read as raw JSON by a second
+/// HTTP client, because yfinance-rs does not return them in that form. Both share every setting
+/// above, including one retry policy; the second keeps its own cookie/crumb session, fetched the
+/// first time one of those endpoints is used.
+#[pyclass]
+#[derive(Clone)]
+struct Client {
+    /// yfinance-rs client for the typed endpoints
+    yf: YfClient,
+    /// Raw JSON endpoints for data yfinance-rs does not expose
+    yahoo: Yahoo,
+}
+
+#[pymethods]
+impl Client {
+    #[new]
+    #[pyo3(signature = (proxy=None, timeout=None, connect_timeout=None, retries=None, backoff=None, user_agent=None, base_url=None, base_urls=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        proxy: Option<&str>,
+        timeout: Option<f64>,
+        connect_timeout: Option<f64>,
+        retries: Option<u32>,
+        backoff: Option<f64>,
+        user_agent: Option<&str>,
+        base_url: Option<&str>,
+        base_urls: Option<HashMap<String, String>>,
+    ) -> PyResult<Self> {
+        let mut builder = YfClient::builder();
+        let mut config = YahooConfig {
+            proxy: proxy.map(str::to_string),
+            user_agent: user_agent.map(str::to_string),
+            ..Default::default()
+        };
+
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(proxy);
+        }
+        // Both HTTP stacks get the same timeouts, with defaults so no request can hang
+        let timeout = timeout
+            .map(|t| seconds("timeout", t))
+            .transpose()?
+            .unwrap_or(DEFAULT_TIMEOUT);
+        let connect_timeout = connect_timeout
+            .map(|t| seconds("connect_timeout", t))
+            .transpose()?
+            .unwrap_or(DEFAULT_CONNECT_TIMEOUT);
+        builder = builder.timeout(timeout).connect_timeout(connect_timeout);
+        config.timeout = Some(timeout);
+        config.connect_timeout = Some(connect_timeout);
+        // Both HTTP stacks follow the same retry policy
+        if let Some(retries) = retries {
+            config.retry.enabled = retries > 0;
+            config.retry.max_retries = retries;
+        }
+        if let Some(backoff) = backoff {
+            config.retry.backoff = Backoff::Exponential {
+                base: seconds("backoff", backoff)?,
+                factor: 2.0,
+                max: Duration::from_secs(30),
+                jitter: true,
+            };
+        }
+        builder = builder.retry_config(config.retry.clone());
+        if let Some(user_agent) = user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        config.endpoints = resolve_endpoints(base_url, base_urls)?;
+        for (name, url) in config.endpoints.iter().cloned() {
+            builder = match name {
+                "chart" => builder.base_chart(url),
+                "quote_summary" => builder.base_quote(url),
+                "quote" => builder.base_quote_api(url),
+                "options" => builder.base_options_v7(url),
+                "timeseries" => builder.base_timeseries(url),
+                "cookie" => builder.cookie_url(url),
+                "crumb" => builder.crumb_url(url),
+                // Only used by the raw client (earnings dates)
+                "visualization" => builder,
+                _ => unreachable!("endpoint names are validated by resolve_endpoints"),
+            };
+        }
+
+        Ok(Client {
+            yf: builder.build().map_err(yf_err)?,
+            yahoo: Yahoo::new(config)?,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        "yfinance.Client()".to_string()
+    }
+}
+
+/// Client for a `session` argument, falling back to the shared default client
+fn session_client(session: Option<PyRef<'_, Client>>) -> PyResult<Client> {
+    session.map_or_else(default_client, |s| Ok(s.clone()))
+}
+
+/// Owned value stored in a [`Record`]
+#[derive(IntoPyObject)]
+enum Value {
+    Str(String),
+    /// A string that may be unknown, converted to `None` when missing
+    OptStr(Option<String>),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Decimal(DecimalText),
+    Bool(bool),
+    StrList(Vec<String>),
+    Date(NaiveDate),
+    DateList(Vec<NaiveDate>),
+    List(Vec<Value>),
+    Record(Record),
+}
+
+/// Decimal text, converted to `decimal.Decimal` on the Python side
+struct DecimalText(String);
+
+impl<'py> IntoPyObject<'py> for DecimalText {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        py.import("decimal")?.getattr("Decimal")?.call1((self.0,))
+    }
+}
+
+impl Value {
+    /// A JSON number as `int` or `float`, or, when `decimal` is set, as a `decimal.Decimal` of
+    /// the number's text exactly as Yahoo sent it (serde_json keeps it with `arbitrary_precision`)
+    fn number(number: &serde_json::Number, decimal: bool) -> Self {
+        if decimal {
+            return Value::Decimal(DecimalText(number.to_string()));
+        }
+        match (number.as_i64(), number.as_u64()) {
+            (Some(i), _) => Value::Int(i),
+            (None, Some(u)) => Value::UInt(u),
+            _ => Value::Float(number.as_f64().unwrap_or(f64::NAN)),
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Self {
+        Value::Str(v)
+    }
+}
+
+impl From<Option<String>> for Value {
+    fn from(v: Option<String>) -> Self {
+        Value::OptStr(v)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(v: &str) -> Self {
+        Value::Str(v.to_string())
+    }
+}
+
+impl From<i64> for Value {
+    fn from(v: i64) -> Self {
+        Value::Int(v)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(v: u64) -> Self {
+        Value::UInt(v)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(v: usize) -> Self {
+        Value::UInt(v as u64)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(v: bool) -> Self {
+        Value::Bool(v)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(v: f64) -> Self {
+        Value::Float(v)
+    }
+}
+
+impl From<Vec<String>> for Value {
+    fn from(v: Vec<String>) -> Self {
+        Value::StrList(v)
+    }
+}
+
+impl From<NaiveDate> for Value {
+    fn from(v: NaiveDate) -> Self {
+        Value::Date(v)
+    }
+}
+
+impl From<Vec<NaiveDate>> for Value {
+    fn from(v: Vec<NaiveDate>) -> Self {
+        Value::DateList(v)
+    }
+}
+
+/// Ordered key/value pairs built without the GIL and converted to a Python dict
+#[derive(Default)]
+struct Record(Vec<(String, Value)>);
+
+impl Record {
+    fn set(&mut self, key: impl Into<String>, value: impl Into<Value>) {
+        self.0.push((key.into(), value.into()));
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Record {
+    type Target = PyDict;
+    type Output = Bound<'py, PyDict>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let dict = PyDict::new(py);
+        for (key, value) in self.0 {
+            dict.set_item(key, value)?;
+        }
+        Ok(dict)
+    }
+}
+
+/// Convert quoteSummary JSON the way yfinance flattens it: `{raw, fmt}` objects become their raw
+/// value, and nulls and empty objects are dropped
+fn json_value(json: &serde_json::Value, decimal: bool) -> Option<Value> {
+    use serde_json::Value as Json;
+    match json {
+        Json::Null => None,
+        Json::Bool(b) => Some(Value::Bool(*b)),
+        Json::Number(n) => Some(Value::number(n, decimal)),
+        Json::String(s) => Some(Value::Str(s.clone())),
+        Json::Array(items) => Some(Value::List(
+            items
+                .iter()
+                .filter_map(|v| json_value(v, decimal))
+                .collect(),
+        )),
+        Json::Object(map) => match (map.get("raw"), map.get("fmt")) {
+            (Some(raw), _) => json_value(raw, decimal),
+            (None, Some(fmt)) => json_value(fmt, decimal),
+            _ if map.is_empty() => None,
+            _ => {
+                let mut record = Record::default();
+                for (key, value) in map {
+                    if let Some(value) = json_value(value, decimal) {
+                        record.set(key.clone(), value);
+                    }
+                }
+                Some(Value::Record(record))
+            }
+        },
+    }
+}
+
+/// Number from a quoteSummary field, accepting plain numbers and `{raw, fmt}` objects
+fn json_f64(json: Option<&serde_json::Value>) -> Option<f64> {
+    let json = json?;
+    json.get("raw").unwrap_or(json).as_f64()
+}
+
+/// Integer (e.g. a Unix timestamp) from a quoteSummary field
+fn json_i64(json: Option<&serde_json::Value>) -> Option<i64> {
+    let json = json?;
+    json.get("raw").unwrap_or(json).as_i64()
+}
+
+static JSON_NULL: serde_json::Value = serde_json::Value::Null;
+
+/// A quoteSummary module by name, or null when Yahoo left it out
+fn module<'a>(
+    modules: &'a serde_json::Map<String, serde_json::Value>,
+    name: &str,
+) -> &'a serde_json::Value {
+    modules.get(name).unwrap_or(&JSON_NULL)
+}
+
+/// Array at `pointer` inside `json`, empty if the field is missing
+fn json_rows<'a>(json: &'a serde_json::Value, pointer: &str) -> &'a [serde_json::Value] {
+    json.pointer(pointer)
+        .and_then(serde_json::Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Float column built from one field of each row
+fn f64_column(name: &str, rows: &[&serde_json::Value], key: &str) -> Column {
+    let values: Vec<Option<f64>> = rows.iter().map(|row| json_f64(row.get(key))).collect();
+    Series::new(name.into(), values).into()
+}
+
+/// Validated arguments for a history request
+#[derive(Clone)]
+struct HistoryRequest {
+    period: Option<String>,
+    start: Option<DateBound>,
+    end: Option<DateBound>,
+    range: Range,
+    interval_str: String,
+    interval: Interval,
+    prepost: bool,
+    auto_adjust: bool,
+    actions: bool,
+}
+
+impl HistoryRequest {
+    fn new(
+        period: Option<&str>,
+        interval: Option<&str>,
+        start: Option<DateArg>,
+        end: Option<DateArg>,
+        prepost: bool,
+        auto_adjust: bool,
+        actions: bool,
+    ) -> PyResult<Self> {
+        let interval_str = interval.unwrap_or("1d");
+        let interval = parse_interval(interval_str)?;
+        let range = parse_range(period.unwrap_or(default_period(interval_str)))?;
+
+        let req = HistoryRequest {
+            period: period.map(str::to_string),
+            start: start.as_ref().map(DateArg::to_bound).transpose()?,
+            end: end.as_ref().map(DateArg::to_bound).transpose()?,
+            range,
+            interval_str: interval_str.to_string(),
+            interval,
+            prepost,
+            auto_adjust,
+            actions,
+        };
+        // Reject bad combinations before any request; the window is resolved again in the
+        // exchange's timezone when plain dates or naive datetimes were given
+        req.window(Tz::UTC)?;
+        Ok(req)
+    }
+
+    /// Whether `start` or `end` is a wall-clock time that depends on the exchange's timezone
+    fn has_local_times(&self) -> bool {
+        [self.start, self.end]
+            .iter()
+            .any(|bound| matches!(bound, Some(DateBound::Local(_))))
+    }
+
+    /// The explicit `[start, end)` window with wall-clock times read in `tz` (None for a plain
+    /// `period`), checked against Yahoo's intraday limits
+    fn window(&self, tz: Tz) -> PyResult<Option<(DateTime<Utc>, DateTime<Utc>)>> {
+        // Without a period, the window (or the one ending at a lone `end`) is the interval's default
+        let period = match (self.period.as_deref(), self.start) {
+            (None, None) => Some(default_period(&self.interval_str)),
+            (period, _) => period,
+        };
+        let window = resolve_history_window(
+            period,
+            self.start.map(|bound| bound.resolve(tz)),
+            self.end.map(|bound| bound.resolve(tz)),
+        )?;
+
+        let now = Utc::now();
+        let (win_start, win_end) =
+            window.unwrap_or_else(|| (period_start(period.unwrap_or("1mo"), now), now));
+        validate_interval_window(&self.interval_str, win_start, win_end, now)?;
+        Ok(window)
+    }
+}
+
+/// Parse an option expiration date (YYYY-MM-DD) into a Unix timestamp
+fn parse_expiration(date: Option<&str>) -> PyResult<Option<i64>> {
+    date.map(|date| {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map(|d| d.and_time(NaiveTime::MIN).and_utc().timestamp())
+            .map_err(|e| InvalidParameterError::new_err(format!("Invalid date format: {}", e)))
+    })
+    .transpose()
+}
+
+// ============ Market state codes ============
+
+/// yfinance market state string (PREPRE, PRE, REGULAR, POST, POSTPOST or CLOSED)
+fn market_state_code(state: impl std::fmt::Display) -> String {
+    let code = state
+        .to_string()
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_ascii_uppercase();
+    match code.as_str() {
+        "PREMARKET" => "PRE".to_string(),
+        "OPEN" => "REGULAR".to_string(),
+        "POSTMARKET" | "AFTERHOURS" => "POST".to_string(),
+        _ => code,
+    }
+}
+
+// ============ Currency codes ============
+
+/// Yahoo currencies that stand for a minor unit, as (Yahoo code, ISO 4217 code of the main unit)
+const MINOR_UNIT_CURRENCIES: &[(&str, &str)] = &[
+    ("GBp", "GBP"),
+    ("GBX", "GBP"),
+    ("ZAc", "ZAR"),
+    ("ILA", "ILS"),
+];
+
+/// ISO 4217 code for a Yahoo currency, and whether prices are quoted in its minor unit
+/// (pence, cents or agorot, e.g. London and Johannesburg listings)
+fn currency_code(currency: &str) -> (String, bool) {
+    match MINOR_UNIT_CURRENCIES
+        .iter()
+        .find(|(yahoo, _)| *yahoo == currency)
+    {
+        Some((_, iso)) => (iso.to_string(), true),
+        None => (currency.to_ascii_uppercase(), false),
+    }
+}
+
+// ============ Fetchers (shared by Ticker and AsyncTicker) ============
+
+/// Exchange timezones by symbol, cached for the life of the process like yfinance's tz cache
+static EXCHANGE_TIMEZONES: OnceLock<std::sync::Mutex<HashMap<String, Tz>>> = OnceLock::new();
+
+/// Timezone of the symbol's exchange from its chart metadata (UTC if Yahoo does not name one)
+async fn exchange_timezone(yahoo: &Yahoo, symbol: &str) -> PyResult<Tz> {
+    let cache = EXCHANGE_TIMEZONES.get_or_init(Default::default);
+    let cached = cache.lock().map_err(to_py_err)?.get(symbol).copied();
+    if let Some(tz) = cached {
+        return Ok(tz);
+    }
+
+    let chart = yahoo
+        .chart(symbol, &[("range", "1d"), ("interval", "1d")])
+        .await?;
+    let tz = chart
+        .get("meta")
+        .and_then(|meta| meta.get("exchangeTimezoneName"))
+        .and_then(serde_json::Value::as_str)
+        .and_then(|name| name.parse::<Tz>().ok())
+        .unwrap_or(Tz::UTC);
+    cache
+        .lock()
+        .map_err(to_py_err)?
+        .insert(symbol.to_string(), tz);
+    Ok(tz)
+}
+
+async fn fetch_history(
+    ticker: YfTicker,
+    yahoo: Yahoo,
+    symbol: String,
+    req: HistoryRequest,
+) -> PyResult<PyDataFrame> {
+    // Plain dates and naive datetimes are exchange-local times, as in yfinance
+    let tz = if req.has_local_times() {
+        exchange_timezone(&yahoo, &symbol).await?
+    } else {
+        Tz::UTC
+    };
+
+    let mut builder = ticker.history_builder();
+    builder = match req.window(tz)? {
+        Some((start, end)) => builder.between(start, end),
+        None => builder.range(req.range),
+    };
+    builder = builder
+        .interval(req.interval)
+        .auto_adjust(req.auto_adjust)
+        .prepost(req.prepost)
+        .actions(req.actions);
+
+    // The chart response carries the window's corporate actions alongside its bars
+    let response = builder.fetch_full().await.map_err(yf_err)?;
+    let df = response.candles.to_dataframe().map_err(parse_err)?;
+    let mut df = convert_ts_to_date(df).map_err(parse_err)?;
+    if req.actions {
+        attach_actions(&mut df, &action_rows(response.actions)).map_err(parse_err)?;
+    }
+    Ok(PyDataFrame(df))
+}
+
+/// One dividend, split or capital gain at a Unix timestamp (seconds)
+struct ActionRow {
+    ts: i64,
+    dividend: Option<f64>,
+    split: Option<f64>,
+    capital_gain: Option<f64>,
+}
+
+fn action_rows(actions: Vec<Action>) -> Vec<ActionRow> {
+    use yfinance_rs::core::conversions::{datetime_to_i64, money_to_f64};
+
+    actions
+        .into_iter()
+        .map(|action| match action {
+            Action::Dividend { ts, amount } => ActionRow {
+                ts: datetime_to_i64(ts),
+                dividend: Some(money_to_f64(&amount)),
+                split: None,
+                capital_gain: None,
+            },
+            Action::Split {
+                ts,
+                numerator,
+                denominator,
+            } => ActionRow {
+                ts: datetime_to_i64(ts),
+                dividend: None,
+                split: Some(numerator as f64 / denominator as f64),
+                capital_gain: None,
+            },
+            Action::CapitalGain { ts, gain } => ActionRow {
+                ts: datetime_to_i64(ts),
+                dividend: None,
+                split: None,
+                capital_gain: Some(money_to_f64(&gain)),
+            },
+        })
+        .collect()
+}
+
+/// Add `dividends` and `stock_splits` columns to a history frame, like yfinance: each action is
+/// summed into the bar containing it (splits multiply) and other bars hold 0.0. `capital_gains`
+/// is only added when the actions include one, as yfinance does for funds.
+fn attach_actions(df: &mut DataFrame, actions: &[ActionRow]) -> PolarsResult<()> {
+    let bars: Vec<i64> = df
+        .column("date")?
+        .cast(&DataType::Int64)?
+        .i64()?
+        .into_iter()
+        .map(|ts| ts.unwrap_or(i64::MIN))
+        .collect();
+    // The last bar is assumed to be as long as the one before it
+    let last_end = match bars.as_slice() {
+        [.., prev, last] => last + (last - prev),
+        [last] => last + 86_400_000,
+        [] => i64::MIN,
+    };
+
+    let mut dividends = vec![0.0; bars.len()];
+    let mut splits = vec![0.0; bars.len()];
+    let mut capital_gains = vec![0.0; bars.len()];
+    for action in actions {
+        let ts = action.ts * 1000;
+        let Some(bar) = bars.partition_point(|&start| start <= ts).checked_sub(1) else {
+            continue;
+        };
+        if ts >= last_end {
+            continue;
+        }
+        if let Some(amount) = action.dividend {
+            dividends[bar] += amount;
+        }
+        if let Some(ratio) = action.split {
+            splits[bar] = if splits[bar] == 0.0 {
+                ratio
+            } else {
+                splits[bar] * ratio
+            };
+        }
+        if let Some(amount) = action.capital_gain {
+            capital_gains[bar] += amount;
+        }
+    }
+
+    df.with_column(Series::new("dividends".into(), dividends))?;
+    df.with_column(Series::new("stock_splits".into(), splits))?;
+    if actions.iter().any(|action| action.capital_gain.is_some()) {
+        df.with_column(Series::new("capital_gains".into(), capital_gains))?;
+    }
+    Ok(())
+}
+
+/// quoteSummary modules merged into `info`, in the order yfinance merges them
+const INFO_MODULES: &[&str] = &[
+    "assetProfile",
+    "summaryDetail",
+    "defaultKeyStatistics",
+    "financialData",
+    "price",
+];
+
+async fn fetch_info(yahoo: Yahoo, symbol: String, decimal: bool) -> PyResult<Record> {
+    let modules = yahoo.quote_summary(&symbol, INFO_MODULES).await?;
+
+    let mut record = Record::default();
+    record.set("symbol", symbol);
+    // The ISIN needs a separate lookup (get_isin), so the key is kept but left as None
+    record.set("isin", None::<String>);
+
+    for name in INFO_MODULES {
+        let Some(serde_json::Value::Object(module)) = modules.get(*name) else {
+            continue;
+        };
+        for (key, value) in module {
+            let value = match key.as_str() {
+                "maxAge" | "symbol" => continue,
+                "marketState" => value.as_str().map(|s| Value::Str(market_state_code(s))),
+                "currency" => {
+                    if let Some(currency) = value.as_str() {
+                        let (code, minor_unit) = currency_code(currency);
+                        record.set("currency", code);
+                        record.set("pricesInMinorUnit", minor_unit);
+                    }
+                    continue;
+                }
+                _ => json_value(value, decimal),
+            };
+            if let Some(value) = value {
+                record.set(key.clone(), value);
+            }
+        }
+    }
+
+    Ok(record)
+}
+
+async fn fetch_dividends(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let divs = ticker.dividends(None).await.map_err(yf_err)?;
+
+    let timestamps: Vec<i64> = divs.iter().map(|(ts, _)| *ts).collect();
+    let amounts: Vec<f64> = divs.iter().map(|(_, amt)| *amt).collect();
+
+    let df = DataFrame::new(vec![
+        timestamps_to_datetime_series_sec(&timestamps, "date").into(),
+        Series::new("dividends".into(), amounts).into(),
+    ])
+    .map_err(to_py_err)?;
+
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_splits(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let splits = ticker.splits(None).await.map_err(yf_err)?;
+
+    let timestamps: Vec<i64> = splits.iter().map(|(ts, _, _)| *ts).collect();
+    let ratios: Vec<f64> = splits
+        .iter()
+        .map(|(_, num, den)| *num as f64 / *den as f64)
+        .collect();
+
+    let df = DataFrame::new(vec![
+        timestamps_to_datetime_series_sec(&timestamps, "date").into(),
+        Series::new("stock_splits".into(), ratios).into(),
+    ])
+    .map_err(to_py_err)?;
+
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_actions(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let rows = action_rows(ticker.actions(None).await.map_err(yf_err)?);
+
+    let timestamps: Vec<i64> = rows.iter().map(|row| row.ts).collect();
+    let dividends: Vec<Option<f64>> = rows.iter().map(|row| row.dividend).collect();
+    let splits: Vec<Option<f64>> = rows.iter().map(|row| row.split).collect();
+    let capital_gains: Vec<Option<f64>> = rows.iter().map(|row| row.capital_gain).collect();
+
+    let df = DataFrame::new(vec![
+        timestamps_to_datetime_series_sec(&timestamps, "date").into(),
+        Series::new("dividends".into(), dividends).into(),
+        Series::new("stock_splits".into(), splits).into(),
+        Series::new("capital_gains".into(), capital_gains).into(),
+    ])
+    .map_err(to_py_err)?;
+
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_capital_gains(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let gains = ticker.capital_gains(None).await.map_err(yf_err)?;
+
+    let timestamps: Vec<i64> = gains.iter().map(|(ts, _)| *ts).collect();
+    let amounts: Vec<f64> = gains.iter().map(|(_, amt)| *amt).collect();
+
+    let df = DataFrame::new(vec![
+        timestamps_to_datetime_series_sec(&timestamps, "date").into(),
+        Series::new("capital_gains".into(), amounts).into(),
+    ])
+    .map_err(to_py_err)?;
+
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_isin(ticker: YfTicker) -> PyResult<Option<String>> {
+    ticker.isin().await.map_err(yf_err)
+}
+
+async fn fetch_income_stmt(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let stmt = ticker.income_stmt(None).await.map_err(yf_err)?;
+    let df = stmt.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_quarterly_income_stmt(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let stmt = ticker.quarterly_income_stmt(None).await.map_err(yf_err)?;
+    let df = stmt.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_balance_sheet(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let stmt = ticker.balance_sheet(None).await.map_err(yf_err)?;
+    let df = stmt.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_quarterly_balance_sheet(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let stmt = ticker.quarterly_balance_sheet(None).await.map_err(yf_err)?;
+    let df = stmt.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_cashflow(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let stmt = ticker.cashflow(None).await.map_err(yf_err)?;
+    let df = stmt.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_quarterly_cashflow(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let stmt = ticker.quarterly_cashflow(None).await.map_err(yf_err)?;
+    let df = stmt.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+/// Yearly or quarterly revenue and earnings from the quoteSummary `earnings` module
+async fn fetch_earnings(yahoo: Yahoo, symbol: String, quarterly: bool) -> PyResult<PyDataFrame> {
+    let modules = yahoo.quote_summary(&symbol, &["earnings"]).await?;
+    let df = earnings_frame(module(&modules, "earnings"), quarterly).map_err(to_py_err)?;
+    Ok(PyDataFrame(df))
+}
+
+fn earnings_frame(earnings: &serde_json::Value, quarterly: bool) -> PolarsResult<DataFrame> {
+    let pointer = if quarterly {
+        "/financialsChart/quarterly"
+    } else {
+        "/financialsChart/yearly"
+    };
+    let rows: Vec<&serde_json::Value> = json_rows(earnings, pointer).iter().collect();
+
+    // Years are integers (2024); quarters are labels such as "3Q2024"
+    let period: Column = if quarterly {
+        let quarters: Vec<Option<&str>> = rows
+            .iter()
+            .map(|row| row.get("date").and_then(serde_json::Value::as_str))
+            .collect();
+        Series::new("quarter".into(), quarters).into()
+    } else {
+        let years: Vec<Option<i64>> = rows.iter().map(|row| json_i64(row.get("date"))).collect();
+        Series::new("year".into(), years).into()
+    };
+
+    DataFrame::new(vec![
+        period,
+        f64_column("revenue", &rows, "revenue"),
+        f64_column("earnings", &rows, "earnings"),
+    ])
+}
+
+/// Reported vs estimated EPS for recent quarters from the quoteSummary `earningsHistory` module
+async fn fetch_earnings_history(yahoo: Yahoo, symbol: String) -> PyResult<PyDataFrame> {
+    let modules = yahoo.quote_summary(&symbol, &["earningsHistory"]).await?;
+    let df = earnings_history_frame(module(&modules, "earningsHistory")).map_err(to_py_err)?;
+    Ok(PyDataFrame(df))
+}
+
+/// Quarters without a date are skipped
+fn earnings_history_frame(history: &serde_json::Value) -> PolarsResult<DataFrame> {
+    let rows: Vec<&serde_json::Value> = json_rows(history, "/history")
+        .iter()
+        .filter(|row| json_i64(row.get("quarter")).is_some())
+        .collect();
+    let quarters: Vec<i64> = rows
+        .iter()
+        .filter_map(|row| json_i64(row.get("quarter")))
+        .collect();
+
+    DataFrame::new(vec![
+        timestamps_to_datetime_series_sec(&quarters, "quarter").into(),
+        f64_column("eps_estimate", &rows, "epsEstimate"),
+        f64_column("eps_actual", &rows, "epsActual"),
+        f64_column("eps_difference", &rows, "epsDifference"),
+        f64_column("surprise_percent", &rows, "surprisePercent"),
+    ])
+}
+
+const EARNINGS_DATE_FIELDS: &[&str] = &[
+    "startdatetime",
+    "timeZoneShortName",
+    "epsestimate",
+    "epsactual",
+    "epssurprisepct",
+    "eventtype",
+];
+
+/// Past and scheduled earnings reports from Yahoo's earnings calendar, newest first
+async fn fetch_earnings_dates(yahoo: Yahoo, symbol: String, limit: usize) -> PyResult<PyDataFrame> {
+    if limit == 0 {
+        return Err(InvalidParameterError::new_err("limit must be at least 1"));
+    }
+    let query = serde_json::json!({
+        "offset": 0,
+        "size": limit,
+        "sortField": "startdatetime",
+        "sortType": "DESC",
+        "entityIdType": "earnings",
+        "includeFields": EARNINGS_DATE_FIELDS,
+        "query": {"operator": "eq", "operands": ["ticker", symbol]},
+    });
+    let (calendar, quote) =
+        futures::join!(yahoo.visualization(&symbol, &query), yahoo.quote(&symbol));
+    let document = calendar?;
+    let timezone = quote.ok().and_then(|quote| {
+        quote
+            .get("exchangeTimezoneName")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+    });
+    let df = earnings_dates_frame(&document, timezone.as_deref(), limit).map_err(to_py_err)?;
+    Ok(PyDataFrame(df))
+}
+
+/// Earnings dates from a visualization document, at most `limit` rows. Times are reported in the
+/// exchange's timezone like yfinance, falling back to UTC when it is missing or unknown
+fn earnings_dates_frame(
+    document: &serde_json::Value,
+    timezone: Option<&str>,
+    limit: usize,
+) -> PolarsResult<DataFrame> {
+    let timezone = timezone
+        .and_then(|name| TimeZone::opt_try_new(Some(name)).ok().flatten())
+        .unwrap_or(TimeZone::UTC);
+
+    let columns = json_rows(document, "/columns");
+    let index = |id: &str| {
+        columns
+            .iter()
+            .position(|c| c.get("id").and_then(serde_json::Value::as_str) == Some(id))
+    };
+    let (date_idx, estimate_idx, actual_idx, surprise_idx) = (
+        index("startdatetime"),
+        index("epsestimate"),
+        index("epsactual"),
+        index("epssurprisepct"),
+    );
+    fn cell(row: &[serde_json::Value], idx: Option<usize>) -> Option<&serde_json::Value> {
+        idx.and_then(|i| row.get(i))
+    }
+
+    let mut dates = Vec::new();
+    let mut estimates = Vec::new();
+    let mut actuals = Vec::new();
+    let mut surprises = Vec::new();
+    let rows = json_rows(document, "/rows")
+        .iter()
+        .filter_map(serde_json::Value::as_array)
+        .map(Vec::as_slice);
+    for row in rows {
+        if dates.len() == limit {
+            break;
+        }
+        // startdatetime is an ISO 8601 string, or epoch milliseconds in older responses
+        let date = match cell(row, date_idx) {
+            Some(serde_json::Value::String(s)) => DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|d| d.timestamp_millis()),
+            other => json_i64(other),
+        };
+        let Some(date) = date else { continue };
+        dates.push(date);
+        estimates.push(json_f64(cell(row, estimate_idx)));
+        actuals.push(json_f64(cell(row, actual_idx)));
+        surprises.push(json_f64(cell(row, surprise_idx)));
+    }
+
+    let earnings_date = Series::new("earnings_date".into(), dates)
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, Some(timezone)))?;
+    DataFrame::new(vec![
+        earnings_date.into(),
+        Column::new("eps_estimate".into(), estimates),
+        Column::new("reported_eps".into(), actuals),
+        Column::new("surprise_percent".into(), surprises),
+    ])
+}
+
+/// EPS and revenue estimate ranges in `calendarEvents.earnings`: (dict key, frame column)
+const CALENDAR_ESTIMATES: &[(&str, &str)] = &[
+    ("earningsHigh", "earnings_high"),
+    ("earningsLow", "earnings_low"),
+    ("earningsAverage", "earnings_average"),
+    ("revenueHigh", "revenue_high"),
+    ("revenueLow", "revenue_low"),
+    ("revenueAverage", "revenue_average"),
+];
+
+/// Calendar as a dict, or as a one-row frame when requested
+#[derive(IntoPyObject)]
+enum Calendar {
+    Dict(Record),
+    Frame(PyDataFrame),
+}
+
+/// UTC date of a quoteSummary Unix timestamp
+fn timestamp_date(json: Option<&serde_json::Value>) -> Option<NaiveDate> {
+    DateTime::from_timestamp(json_i64(json)?, 0).map(|dt| dt.date_naive())
+}
+
+async fn fetch_calendar(yahoo: Yahoo, symbol: String, as_frame: bool) -> PyResult<Calendar> {
+    let modules = yahoo.quote_summary(&symbol, &["calendarEvents"]).await?;
+    calendar(module(&modules, "calendarEvents"), symbol, as_frame).map_err(to_py_err)
+}
+
+/// Dates and estimates Yahoo leaves out are omitted from the dict and null in the frame
+fn calendar(events: &serde_json::Value, symbol: String, as_frame: bool) -> PolarsResult<Calendar> {
+    let earnings = events.get("earnings").unwrap_or(&JSON_NULL);
+
+    let earnings_dates: Vec<NaiveDate> = json_rows(earnings, "/earningsDate")
+        .iter()
+        .filter_map(|d| timestamp_date(Some(d)))
+        .collect();
+    let ex_dividend_date = timestamp_date(events.get("exDividendDate"));
+    let dividend_date = timestamp_date(events.get("dividendDate"));
+    let estimates: Vec<Option<f64>> = CALENDAR_ESTIMATES
+        .iter()
+        .map(|(key, _)| json_f64(earnings.get(*key)))
+        .collect();
+
+    if as_frame {
+        let mut columns = vec![
+            Column::new("symbol".into(), [symbol]),
+            Column::new(
+                "earnings_dates".into(),
+                [Series::new(PlSmallStr::EMPTY, earnings_dates)],
+            ),
+            Column::new("ex_dividend_date".into(), [ex_dividend_date]),
+            Column::new("dividend_date".into(), [dividend_date]),
+        ];
+        for ((_, name), value) in CALENDAR_ESTIMATES.iter().zip(estimates) {
+            columns.push(Column::new((*name).into(), [value]));
+        }
+        let df = DataFrame::new(columns)?;
+        return Ok(Calendar::Frame(PyDataFrame(df)));
+    }
+
+    let mut record = Record::default();
+    record.set("symbol", symbol);
+    record.set("earningsDates", earnings_dates);
+    if let Some(date) = ex_dividend_date {
+        record.set("exDividendDate", date);
+    }
+    if let Some(date) = dividend_date {
+        record.set("dividendDate", date);
+    }
+    for ((key, _), value) in CALENDAR_ESTIMATES.iter().zip(estimates) {
+        if let Some(value) = value {
+            record.set(*key, value);
+        }
+    }
+
+    Ok(Calendar::Dict(record))
+}
+
+/// Periods reported by the earningsTrend module: current/next quarter and current/next year
+const ESTIMATE_PERIODS: &[&str] = &["0q", "+1q", "0y", "+1y"];
+
+// (column name, Yahoo key) for each earningsTrend section
+const EARNINGS_ESTIMATE_FIELDS: &[(&str, &str)] = &[
+    ("avg", "avg"),
+    ("low", "low"),
+    ("high", "high"),
+    ("year_ago_eps", "yearAgoEps"),
+    ("number_of_analysts", "numberOfAnalysts"),
+    ("growth", "growth"),
+];
+
+const REVENUE_ESTIMATE_FIELDS: &[(&str, &str)] = &[
+    ("avg", "avg"),
+    ("low", "low"),
+    ("high", "high"),
+    ("number_of_analysts", "numberOfAnalysts"),
+    ("year_ago_revenue", "yearAgoRevenue"),
+    ("growth", "growth"),
+];
+
+const EPS_TREND_FIELDS: &[(&str, &str)] = &[
+    ("current", "current"),
+    ("7_days_ago", "7daysAgo"),
+    ("30_days_ago", "30daysAgo"),
+    ("60_days_ago", "60daysAgo"),
+    ("90_days_ago", "90daysAgo"),
+];
+
+const EPS_REVISIONS_FIELDS: &[(&str, &str)] = &[
+    ("up_last_7_days", "upLast7days"),
+    ("up_last_30_days", "upLast30days"),
+    ("down_last_30_days", "downLast30days"),
+    ("down_last_7_days", "downLast7Days"),
+];
+
+/// Fields holding analyst counts rather than amounts
+const COUNT_KEYS: &[&str] = &[
+    "numberOfAnalysts",
+    "upLast7days",
+    "upLast30days",
+    "downLast30days",
+    "downLast7Days",
+];
+
+/// Period string of an earningsTrend/indexTrend entry
+fn trend_period(row: &serde_json::Value) -> Option<&str> {
+    row.get("period").and_then(serde_json::Value::as_str)
+}
+
+/// One row per estimate period with `fields` read from the `section` object of the earningsTrend module
+async fn fetch_earnings_trend(
+    yahoo: Yahoo,
+    symbol: String,
+    section: &'static str,
+    fields: &'static [(&'static str, &'static str)],
+) -> PyResult<PyDataFrame> {
+    let modules = yahoo.quote_summary(&symbol, &["earningsTrend"]).await?;
+    let df = earnings_trend_frame(module(&modules, "earningsTrend"), section, fields)
+        .map_err(to_py_err)?;
+    Ok(PyDataFrame(df))
+}
+
+/// Rows for periods other than ESTIMATE_PERIODS (e.g. the long-term +5y) are dropped
+fn earnings_trend_frame(
+    earnings_trend: &serde_json::Value,
+    section: &str,
+    fields: &[(&str, &str)],
+) -> PolarsResult<DataFrame> {
+    let trend: Vec<&serde_json::Value> = json_rows(earnings_trend, "/trend")
+        .iter()
+        .filter(|row| trend_period(row).is_some_and(|p| ESTIMATE_PERIODS.contains(&p)))
+        .collect();
+    let periods: Vec<&str> = trend.iter().filter_map(|row| trend_period(row)).collect();
+    let rows: Vec<&serde_json::Value> = trend
+        .iter()
+        .map(|row| row.get(section).unwrap_or(&JSON_NULL))
+        .collect();
+
+    let mut columns = vec![Column::new("period".into(), periods)];
+    for (name, key) in fields {
+        if COUNT_KEYS.contains(key) {
+            let counts: Vec<Option<i64>> = rows.iter().map(|row| json_i64(row.get(*key))).collect();
+            columns.push(Column::new((*name).into(), counts));
+        } else {
+            columns.push(f64_column(name, &rows, key));
+        }
+    }
+    DataFrame::new(columns)
+}
+
+/// Expected growth per period for the stock and its index (earningsTrend and indexTrend modules)
+async fn fetch_growth_estimates(yahoo: Yahoo, symbol: String) -> PyResult<PyDataFrame> {
+    let modules = yahoo
+        .quote_summary(&symbol, &["earningsTrend", "indexTrend"])
+        .await?;
+    let df = growth_estimates_frame(
+        module(&modules, "earningsTrend"),
+        module(&modules, "indexTrend"),
+    )
+    .map_err(to_py_err)?;
+    Ok(PyDataFrame(df))
+}
+
+fn growth_estimates_frame(
+    earnings_trend: &serde_json::Value,
+    index_trend: &serde_json::Value,
+) -> PolarsResult<DataFrame> {
+    let trend = json_rows(earnings_trend, "/trend");
+    let index_trend = json_rows(index_trend, "/estimates");
+
+    // Stock periods first, then any the index reports on its own (e.g. +5y)
+    let mut periods: Vec<&str> = trend.iter().filter_map(trend_period).collect();
+    for period in index_trend.iter().filter_map(trend_period) {
+        if !periods.contains(&period) {
+            periods.push(period);
+        }
+    }
+    let growth = |rows: &[serde_json::Value], period: &str| {
+        rows.iter()
+            .find(|row| trend_period(row) == Some(period))
+            .and_then(|row| json_f64(row.get("growth")))
+    };
+    let stock: Vec<Option<f64>> = periods.iter().map(|p| growth(trend, p)).collect();
+    let index: Vec<Option<f64>> = periods.iter().map(|p| growth(index_trend, p)).collect();
+
+    DataFrame::new(vec![
+        Column::new("period".into(), periods),
+        Column::new("stock_trend".into(), stock),
+        Column::new("index_trend".into(), index),
+    ])
+}
+
+async fn fetch_recommendations(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let recs = ticker.recommendations().await.map_err(yf_err)?;
+    let df = recs.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+/// Consensus price targets from the financialData module, keyed like yfinance
+async fn fetch_analyst_price_targets(yahoo: Yahoo, symbol: String) -> PyResult<Record> {
+    let modules = yahoo.quote_summary(&symbol, &["financialData"]).await?;
+    Ok(price_targets(module(&modules, "financialData")))
+}
+
+/// Targets Yahoo leaves out are omitted from the record
+fn price_targets(financial_data: &serde_json::Value) -> Record {
+    let mut record = Record::default();
+    for (name, key) in [
+        ("current", "currentPrice"),
+        ("high", "targetHighPrice"),
+        ("low", "targetLowPrice"),
+        ("mean", "targetMeanPrice"),
+        ("median", "targetMedianPrice"),
+    ] {
+        if let Some(value) = json_f64(financial_data.get(key)) {
+            record.set(name, value);
+        }
+    }
+    record
+}
+
+/// Analyst rating counts per month (`0m` is the current month) from the recommendationTrend module
+async fn fetch_recommendations_summary(yahoo: Yahoo, symbol: String) -> PyResult<PyDataFrame> {
+    let modules = yahoo
+        .quote_summary(&symbol, &["recommendationTrend"])
+        .await?;
+    let df = recommendations_summary_frame(module(&modules, "recommendationTrend"))
+        .map_err(to_py_err)?;
+    Ok(PyDataFrame(df))
+}
+
+fn recommendations_summary_frame(trend: &serde_json::Value) -> PolarsResult<DataFrame> {
+    let rows = json_rows(trend, "/trend");
+    let periods: Vec<Option<&str>> = rows.iter().map(trend_period).collect();
+
+    let mut columns = vec![Column::new("period".into(), periods)];
+    for (name, key) in [
+        ("strong_buy", "strongBuy"),
+        ("buy", "buy"),
+        ("hold", "hold"),
+        ("sell", "sell"),
+        ("strong_sell", "strongSell"),
+    ] {
+        let counts: Vec<Option<i64>> = rows.iter().map(|row| json_i64(row.get(key))).collect();
+        columns.push(Column::new(name.into(), counts));
+    }
+    DataFrame::new(columns)
+}
+
+async fn fetch_upgrades_downgrades(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let data = ticker.upgrades_downgrades().await.map_err(yf_err)?;
+    let df = data.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_major_holders(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let holders = ticker.major_holders().await.map_err(yf_err)?;
+
+    let categories: Vec<String> = holders.iter().map(|h| h.category.clone()).collect();
+    let values: Vec<String> = holders.iter().map(|h| h.value.to_string()).collect();
+
+    let df = DataFrame::new(vec![
+        Series::new("Breakdown".into(), categories).into(),
+        Series::new("Value".into(), values).into(),
+    ])
+    .map_err(to_py_err)?;
+
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_institutional_holders(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let holders = ticker.institutional_holders().await.map_err(yf_err)?;
+    let df = holders.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_mutualfund_holders(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let holders = ticker.mutual_fund_holders().await.map_err(yf_err)?;
+    let df = holders.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_insider_transactions(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let txns = ticker.insider_transactions().await.map_err(yf_err)?;
+    let df = txns.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_insider_roster_holders(ticker: YfTicker) -> PyResult<PyDataFrame> {
+    let roster = ticker.insider_roster_holders().await.map_err(yf_err)?;
+    let df = roster.to_dataframe().map_err(parse_err)?;
+    Ok(PyDataFrame(df))
+}
+
+async fn fetch_options(ticker: YfTicker) -> PyResult<Vec<String>> {
+    let timestamps = ticker.options().await.map_err(yf_err)?;
+
+    // Convert Unix timestamps to YYYY-MM-DD strings
+    let dates: Vec<String> = timestamps
+        .iter()
+        .map(|ts| {
+            chrono::DateTime::from_timestamp(*ts, 0)
+                .map(|dt| dt.format("%Y-%m-%d").to_string())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    Ok(dates)
+}
+
+async fn fetch_option_chain(
+    ticker: YfTicker,
+    timestamp: Option<i64>,
+) -> PyResult<(PyDataFrame, PyDataFrame)> {
+    let chain = ticker.option_chain(timestamp).await.map_err(yf_err)?;
+
+    let calls_df = chain.calls.to_dataframe().map_err(parse_err)?;
+    let puts_df = chain.puts.to_dataframe().map_err(parse_err)?;
+
+    Ok((PyDataFrame(calls_df), PyDataFrame(puts_df)))
+}
+
+// ============ FastInfo ============
+
+/// FastInfo keys as (yfinance key, attribute name)
+const FAST_INFO_KEYS: &[(&str, &str)] = &[
+    ("currency", "currency"),
+    ("dayHigh", "day_high"),
+    ("dayLow", "day_low"),
+    ("exchange", "exchange"),
+    ("fiftyDayAverage", "fifty_day_average"),
+    ("lastPrice", "last_price"),
+    ("lastVolume", "last_volume"),
+    ("marketCap", "market_cap"),
+    ("open", "open"),
+    ("previousClose", "previous_close"),
+    ("pricesInMinorUnit", "prices_in_minor_unit"),
+    ("quoteType", "quote_type"),
+    (
+        "regularMarketPreviousClose",
+        "regular_market_previous_close",
+    ),
+    ("shares", "shares"),
+    ("tenDayAverageVolume", "ten_day_average_volume"),
+    ("threeMonthAverageVolume", "three_month_average_volume"),
+    ("timezone", "timezone"),
+    ("twoHundredDayAverage", "two_hundred_day_average"),
+    ("yearChange", "year_change"),
+    ("yearHigh", "year_high"),
+    ("yearLow", "year_low"),
+];
+
+/// Daily closes over the last year, oldest first
+async fn fetch_year_closes(yahoo: Yahoo, symbol: String) -> PyResult<Vec<f64>> {
+    let chart = yahoo
+        .chart(&symbol, &[("range", "1y"), ("interval", "1d")])
+        .await?;
+    let closes = chart
+        .get("indicators")
+        .and_then(|v| v.pointer("/quote/0/close"))
+        .and_then(serde_json::Value::as_array)
+        .ok_or_else(|| parse_err(format!("{}: chart response without closes", symbol)))?;
+    Ok(closes
+        .iter()
+        .filter_map(serde_json::Value::as_f64)
+        .collect())
+}
+
+/// Quick-access price metrics, fetched on first access like yfinance's FastInfo.
+///
+/// Values are available as attributes (`fi.last_price`) or by yfinance key (`fi["lastPrice"]`).
+/// Most values come from the quote endpoint; `year_change` uses one year of daily closes.
+#[pyclass]
+struct FastInfo {
+    symbol: String,
+    client: Client,
+    quote: OnceLock<serde_json::Map<String, serde_json::Value>>,
+    closes: OnceLock<Vec<f64>>,
+}
+
+impl FastInfo {
+    fn new(symbol: String, client: Client) -> Self {
+        FastInfo {
+            symbol,
+            client,
+            quote: OnceLock::new(),
+            closes: OnceLock::new(),
+        }
+    }
+
+    fn quote(&self, py: Python<'_>) -> PyResult<&serde_json::Map<String, serde_json::Value>> {
+        if let Some(quote) = self.quote.get() {
+            return Ok(quote);
+        }
+        let (yahoo, symbol) = (self.client.yahoo.clone(), self.symbol.clone());
+        let quote = block_on(py, async move { yahoo.quote(&symbol).await })?;
+        Ok(self.quote.get_or_init(|| quote))
+    }
+
+    fn closes(&self, py: Python<'_>) -> PyResult<&[f64]> {
+        if let Some(closes) = self.closes.get() {
+            return Ok(closes);
+        }
+        let closes = block_on(
+            py,
+            fetch_year_closes(self.client.yahoo.clone(), self.symbol.clone()),
+        )?;
+        Ok(self.closes.get_or_init(|| closes))
+    }
+
+    fn float(&self, py: Python<'_>, key: &str) -> PyResult<Option<f64>> {
+        Ok(self.quote(py)?.get(key).and_then(serde_json::Value::as_f64))
+    }
+
+    fn int(&self, py: Python<'_>, key: &str) -> PyResult<Option<i64>> {
+        Ok(self.quote(py)?.get(key).and_then(serde_json::Value::as_i64))
+    }
+
+    fn string(&self, py: Python<'_>, key: &str) -> PyResult<Option<String>> {
+        Ok(self
+            .quote(py)?
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string))
+    }
+
+    fn attribute_name(key: &str) -> Option<&'static str> {
+        FAST_INFO_KEYS
+            .iter()
+            .find(|(camel, snake)| *camel == key || *snake == key)
+            .map(|(_, snake)| *snake)
+    }
+}
+
+/// Fetch the quote and closes up front, for AsyncTicker
+async fn fetch_fast_info(client: Client, symbol: String) -> PyResult<FastInfo> {
+    let (quote, closes) = futures::try_join!(
+        client.yahoo.quote(&symbol),
+        fetch_year_closes(client.yahoo.clone(), symbol.clone())
+    )?;
+    let fast_info = FastInfo::new(symbol, client);
+    let _ = fast_info.quote.set(quote);
+    let _ = fast_info.closes.set(closes);
+    Ok(fast_info)
+}
+
+#[pymethods]
+impl FastInfo {
+    /// ISO 4217 currency code, e.g. "GBP" for a listing Yahoo quotes in pence ("GBp")
+    #[getter]
+    fn currency(&self, py: Python<'_>) -> PyResult<Option<String>> {
+        Ok(self
+            .string(py, "currency")?
+            .map(|currency| currency_code(&currency).0))
+    }
+
+    #[getter]
+    fn day_high(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "regularMarketDayHigh")
+    }
+
+    #[getter]
+    fn day_low(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "regularMarketDayLow")
+    }
+
+    /// Yahoo exchange code, e.g. "NMS"
+    #[getter]
+    fn exchange(&self, py: Python<'_>) -> PyResult<Option<String>> {
+        self.string(py, "exchange")
+    }
+
+    #[getter]
+    fn fifty_day_average(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "fiftyDayAverage")
+    }
+
+    #[getter]
+    fn last_price(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "regularMarketPrice")
+    }
+
+    #[getter]
+    fn last_volume(&self, py: Python<'_>) -> PyResult<Option<i64>> {
+        self.int(py, "regularMarketVolume")
+    }
+
+    /// Market capitalisation, or shares outstanding times the last price when Yahoo omits it
+    #[getter]
+    fn market_cap(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        if let Some(market_cap) = self.float(py, "marketCap")? {
+            return Ok(Some(market_cap));
+        }
+        Ok(self
+            .float(py, "sharesOutstanding")?
+            .zip(self.last_price(py)?)
+            .map(|(shares, price)| shares * price))
+    }
+
+    #[getter]
+    fn open(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "regularMarketOpen")
+    }
+
+    #[getter]
+    fn previous_close(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "regularMarketPreviousClose")
+    }
+
+    /// Whether prices are quoted in the currency's minor unit (e.g. pence for "GBP")
+    #[getter]
+    fn prices_in_minor_unit(&self, py: Python<'_>) -> PyResult<Option<bool>> {
+        Ok(self
+            .string(py, "currency")?
+            .map(|currency| currency_code(&currency).1))
+    }
+
+    /// Yahoo quote type, e.g. "EQUITY" or "ETF"
+    #[getter]
+    fn quote_type(&self, py: Python<'_>) -> PyResult<Option<String>> {
+        self.string(py, "quoteType")
+    }
+
+    #[getter]
+    fn regular_market_previous_close(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "regularMarketPreviousClose")
+    }
+
+    /// Shares outstanding
+    #[getter]
+    fn shares(&self, py: Python<'_>) -> PyResult<Option<i64>> {
+        self.int(py, "sharesOutstanding")
+    }
+
+    #[getter]
+    fn ten_day_average_volume(&self, py: Python<'_>) -> PyResult<Option<i64>> {
+        self.int(py, "averageDailyVolume10Day")
+    }
+
+    #[getter]
+    fn three_month_average_volume(&self, py: Python<'_>) -> PyResult<Option<i64>> {
+        self.int(py, "averageDailyVolume3Month")
+    }
+
+    /// Exchange time zone name, e.g. "America/New_York"
+    #[getter]
+    fn timezone(&self, py: Python<'_>) -> PyResult<Option<String>> {
+        self.string(py, "exchangeTimezoneName")
+    }
+
+    #[getter]
+    fn two_hundred_day_average(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "twoHundredDayAverage")
+    }
+
+    /// Change over the last year as a fraction (last close / first close - 1)
+    #[getter]
+    fn year_change(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        let closes = self.closes(py)?;
+        Ok(match (closes.first(), closes.last()) {
+            (Some(first), Some(last)) if *first != 0.0 => Some(last / first - 1.0),
+            _ => None,
+        })
+    }
+
+    #[getter]
+    fn year_high(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "fiftyTwoWeekHigh")
+    }
+
+    #[getter]
+    fn year_low(&self, py: Python<'_>) -> PyResult<Option<f64>> {
+        self.float(py, "fiftyTwoWeekLow")
+    }
+
+    /// yfinance keys, in alphabetical order
+    fn keys(&self) -> Vec<&'static str> {
+        FAST_INFO_KEYS.iter().map(|(camel, _)| *camel).collect()
+    }
+
+    /// (key, value) pairs for every key; fetches everything not loaded yet
+    fn items<'py>(slf: &Bound<'py, Self>) -> PyResult<Vec<(&'static str, Bound<'py, PyAny>)>> {
+        FAST_INFO_KEYS
+            .iter()
+            .map(|(camel, snake)| Ok((*camel, slf.getattr(*snake)?)))
+            .collect()
+    }
+
+    /// Value for a key, or `default` if the key is unknown
+    #[pyo3(signature = (key, default=None))]
+    fn get<'py>(
+        slf: &Bound<'py, Self>,
+        key: &str,
+        default: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match Self::attribute_name(key) {
+            Some(attr) => slf.getattr(attr),
+            None => Ok(default.unwrap_or_else(|| slf.py().None().into_bound(slf.py()))),
+        }
+    }
+
+    /// Look up a value by yfinance key (`"lastPrice"`) or attribute name (`"last_price"`)
+    fn __getitem__<'py>(slf: &Bound<'py, Self>, key: &str) -> PyResult<Bound<'py, PyAny>> {
+        match Self::attribute_name(key) {
+            Some(attr) => slf.getattr(attr),
+            None => Err(pyo3::exceptions::PyKeyError::new_err(key.to_string())),
+        }
+    }
+
+    fn __contains__(&self, key: &str) -> bool {
+        Self::attribute_name(key).is_some()
+    }
+
+    fn __len__(&self) -> usize {
+        FAST_INFO_KEYS.len()
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(self.keys().into_pyobject(py)?.try_iter()?.into_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("yfinance.FastInfo('{}')", self.symbol)
+    }
+}
+
+/// Ticker class for fetching stock data (yfinance-compatible API)
+#[pyclass]
+struct Ticker {
+    symbol: String,
+    client: Client,
+    fast_info: OnceLock<Py<FastInfo>>,
+}
+
+impl Ticker {
+    fn yf_ticker(&self) -> YfTicker {
+        YfTicker::new(&self.client.yf, &self.symbol)
+    }
+
+    fn yahoo(&self) -> Yahoo {
+        self.client.yahoo.clone()
+    }
+}
+
+#[pymethods]
+impl Ticker {
+    #[new]
+    #[pyo3(signature = (symbol, session=None))]
+    fn new(symbol: String, session: Option<PyRef<'_, Client>>) -> PyResult<Self> {
+        Ok(Ticker {
+            symbol,
+            client: session_client(session)?,
+            fast_info: OnceLock::new(),
+        })
+    }
+
+    /// Get historical OHLCV data as a Polars DataFrame
+    ///
+    /// Args:
+    ///     period: Data period (1d, 5d, 1mo, 3mo, 6mo, 1y, 2y, 5y, 10y, ytd, max; default 1mo,
+    ///         or 5d for 1m bars)
+    ///     interval: Data interval (1m, 2m, 5m, 15m, 30m, 60m, 90m, 1h, 1d, 5d, 1wk, 1mo, 3mo)
+    ///     start: Start date/datetime, inclusive (str, date or datetime; dates and naive
+    ///         datetimes are read in the exchange's timezone)
+    ///     end: End date/datetime, exclusive (same forms as start)
+    ///     prepost: Include pre and post market data
+    ///     auto_adjust: Adjust prices for splits and dividends
+    ///     actions: Add dividends and stock_splits columns (and capital_gains for funds)
+    #[pyo3(signature = (period=None, interval=None, start=None, end=None, prepost=false, auto_adjust=true, actions=true))]
+    #[allow(clippy::too_many_arguments)]
+    fn history(
+        &self,
+        py: Python<'_>,
+        period: Option<&str>,
+        interval: Option<&str>,
+        start: Option<DateArg>,
+        end: Option<DateArg>,
+        prepost: bool,
+        auto_adjust: bool,
+        actions: bool,
+    ) -> PyResult<PyDataFrame> {
+        let req = HistoryRequest::new(period, interval, start, end, prepost, auto_adjust, actions)?;
+        block_on(
+            py,
+            fetch_history(self.yf_ticker(), self.yahoo(), self.symbol.clone(), req),
+        )
+    }
+
+    /// Get ticker info as a dictionary
+    #[getter]
+    fn info(&self, py: Python<'_>) -> PyResult<Record> {
+        self.get_info(py, false)
+    }
+
+    /// Get ticker info as a dictionary
+    ///
+    /// Args:
+    ///     decimal: Return every number, integers included, as a decimal.Decimal of the exact
+    ///         value Yahoo sent instead of int/float
+    #[pyo3(signature = (decimal=false))]
+    fn get_info(&self, py: Python<'_>, decimal: bool) -> PyResult<Record> {
+        block_on(py, fetch_info(self.yahoo(), self.symbol.clone(), decimal))
+    }
+
+    /// Get quick-access price metrics, fetched lazily on first access
+    ///
+    /// The same FastInfo is returned on every access, so values load once per Ticker.
+    #[getter]
+    fn fast_info(&self, py: Python<'_>) -> PyResult<Py<FastInfo>> {
+        if let Some(fast_info) = self.fast_info.get() {
+            return Ok(fast_info.clone_ref(py));
+        }
+        let fast_info = Py::new(py, FastInfo::new(self.symbol.clone(), self.client.clone()))?;
+        Ok(self.fast_info.get_or_init(|| fast_info).clone_ref(py))
+    }
+
+    /// Get dividend history as a Polars DataFrame
+    #[getter]
+    fn dividends(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_dividends(self.yf_ticker()))
+    }
+
+    /// Get stock splits history as a Polars DataFrame
+    #[getter]
+    fn splits(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_splits(self.yf_ticker()))
+    }
+
+    /// Get corporate actions (dividends, splits and capital gains) as a Polars DataFrame
+    #[getter]
+    fn actions(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_actions(self.yf_ticker()))
+    }
+
+    /// Get capital gains as a Polars DataFrame
+    #[getter]
+    fn capital_gains(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_capital_gains(self.yf_ticker()))
+    }
+
+    /// Get the ISIN for this ticker
+    fn get_isin(&self, py: Python<'_>) -> PyResult<Option<String>> {
+        block_on(py, fetch_isin(self.yf_ticker()))
+    }
+
+    // ============ Phase 2: Financial Statements ============
+
+    /// Get annual income statement as a Polars DataFrame
+    #[getter]
+    fn income_stmt(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_income_stmt(self.yf_ticker()))
+    }
+
+    /// Get quarterly income statement as a Polars DataFrame
+    #[getter]
+    fn quarterly_income_stmt(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_quarterly_income_stmt(self.yf_ticker()))
+    }
+
+    /// Get annual balance sheet as a Polars DataFrame
+    #[getter]
+    fn balance_sheet(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_balance_sheet(self.yf_ticker()))
+    }
+
+    /// Get quarterly balance sheet as a Polars DataFrame
+    #[getter]
+    fn quarterly_balance_sheet(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_quarterly_balance_sheet(self.yf_ticker()))
+    }
+
+    /// Get annual cash flow statement as a Polars DataFrame
+    #[getter]
+    fn cashflow(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_cashflow(self.yf_ticker()))
+    }
+
+    /// Get quarterly cash flow statement as a Polars DataFrame
+    #[getter]
+    fn quarterly_cashflow(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_quarterly_cashflow(self.yf_ticker()))
+    }
+
+    /// Get the trailing twelve months income statement as a Polars DataFrame
+    #[getter]
+    fn ttm_income_stmt(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::Income,
+                Freq::Trailing,
+                Layout::Wide,
+            ),
+        )
+    }
+
+    /// Get the trailing twelve months cash flow statement as a Polars DataFrame
+    #[getter]
+    fn ttm_cashflow(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::CashFlow,
+                Freq::Trailing,
+                Layout::Wide,
+            ),
+        )
+    }
+
+    /// Get the income statement with yfinance line-item names as a Polars DataFrame
+    ///
+    /// Args:
+    ///     freq: Reporting frequency (yearly, quarterly, trailing)
+    ///     layout: "long" (date, line_item, value), "wide" (one row per date, one column per
+    ///         line item) or "yfinance" (one row per line item, one column per date)
+    #[pyo3(signature = (freq="yearly", layout="wide"))]
+    fn get_income_stmt(&self, py: Python<'_>, freq: &str, layout: &str) -> PyResult<PyDataFrame> {
+        let (freq, layout) = (Freq::parse(freq)?, Layout::parse(layout)?);
+        block_on(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::Income,
+                freq,
+                layout,
+            ),
+        )
+    }
+
+    /// Get the balance sheet with yfinance line-item names as a Polars DataFrame
+    ///
+    /// Args:
+    ///     freq: Reporting frequency (yearly, quarterly)
+    ///     layout: "long" (date, line_item, value), "wide" (one row per date, one column per
+    ///         line item) or "yfinance" (one row per line item, one column per date)
+    #[pyo3(signature = (freq="yearly", layout="wide"))]
+    fn get_balance_sheet(&self, py: Python<'_>, freq: &str, layout: &str) -> PyResult<PyDataFrame> {
+        let (freq, layout) = (Freq::parse(freq)?, Layout::parse(layout)?);
+        block_on(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::BalanceSheet,
+                freq,
+                layout,
+            ),
+        )
+    }
+
+    /// Get the cash flow statement with yfinance line-item names as a Polars DataFrame
+    ///
+    /// Args:
+    ///     freq: Reporting frequency (yearly, quarterly, trailing)
+    ///     layout: "long" (date, line_item, value), "wide" (one row per date, one column per
+    ///         line item) or "yfinance" (one row per line item, one column per date)
+    #[pyo3(signature = (freq="yearly", layout="wide"))]
+    fn get_cashflow(&self, py: Python<'_>, freq: &str, layout: &str) -> PyResult<PyDataFrame> {
+        let (freq, layout) = (Freq::parse(freq)?, Layout::parse(layout)?);
+        block_on(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::CashFlow,
+                freq,
+                layout,
+            ),
+        )
+    }
+
+    /// Get fundamentals timeseries as a long Polars DataFrame (date, key, value, currency)
+    ///
+    /// Args:
+    ///     keys: Line items such as "DilutedAverageShares" or "TangibleBookValue" (default: every
+    ///         income statement, balance sheet and cash flow item)
+    ///     freq: Reporting frequency (annual, quarterly, trailing; "yearly" is an alias of annual)
+    ///     start: First report date to include (str, date or datetime)
+    ///     end: Last report date to include (str, date or datetime; default today)
+    #[pyo3(signature = (keys=None, freq="annual", start=None, end=None))]
+    fn get_fundamentals(
+        &self,
+        py: Python<'_>,
+        keys: Option<Vec<String>>,
+        freq: &str,
+        start: Option<DateArg>,
+        end: Option<DateArg>,
+    ) -> PyResult<PyDataFrame> {
+        let freq = Freq::parse(freq)?;
+        let (start, end) = timestamp_range(start, end)?;
+        block_on(
+            py,
+            fundamentals::fetch_fundamentals(
+                self.yahoo(),
+                self.symbol.clone(),
+                keys,
+                freq,
+                start,
+                end,
+            ),
+        )
+    }
+
+    /// Get financial ratios per report date as a Polars DataFrame
+    ///
+    /// Columns: date, gross_margin, operating_margin, net_margin, roe, roa, roic, current_ratio,
+    /// debt_to_equity, interest_coverage, fcf_yield, asset_turnover. A ratio is null when a line
+    /// item it needs is missing or its denominator is zero.
+    ///
+    /// Args:
+    ///     freq: Reporting frequency (yearly, quarterly; quarterly returns and turnover use
+    ///         trailing-twelve-month flows)
+    #[pyo3(signature = (freq="yearly"))]
+    fn ratios(&self, py: Python<'_>, freq: &str) -> PyResult<PyDataFrame> {
+        let freq = Freq::parse(freq)?;
+        block_on(
+            py,
+            ratios::fetch_ratios(self.yahoo(), self.symbol.clone(), freq),
+        )
+    }
+
+    /// Get the history of shares outstanding as a Polars DataFrame (date, shares)
+    ///
+    /// Args:
+    ///     start: First date to include (str, date or datetime; default 548 days before end)
+    ///     end: Last date to include (str, date or datetime; default now)
+    #[pyo3(signature = (start=None, end=None))]
+    fn get_shares_full(
+        &self,
+        py: Python<'_>,
+        start: Option<DateArg>,
+        end: Option<DateArg>,
+    ) -> PyResult<PyDataFrame> {
+        let (start, end) = timestamp_range(start, end)?;
+        block_on(
+            py,
+            fundamentals::fetch_shares_full(self.yahoo(), self.symbol.clone(), start, end),
+        )
+    }
+
+    /// Get yearly revenue and earnings as a Polars DataFrame
+    #[getter]
+    fn earnings(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_earnings(self.yahoo(), self.symbol.clone(), false))
+    }
+
+    /// Get quarterly revenue and earnings as a Polars DataFrame
+    #[getter]
+    fn quarterly_earnings(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_earnings(self.yahoo(), self.symbol.clone(), true))
+    }
+
+    /// Get reported vs estimated EPS for recent quarters as a Polars DataFrame
+    #[getter]
+    fn earnings_history(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fetch_earnings_history(self.yahoo(), self.symbol.clone()),
+        )
+    }
+
+    /// Get past and upcoming earnings dates with EPS estimates and surprises as a Polars DataFrame
+    #[getter]
+    fn earnings_dates(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.get_earnings_dates(py, 12)
+    }
+
+    /// Get up to `limit` past and upcoming earnings dates, newest first
+    #[pyo3(signature = (limit=12))]
+    fn get_earnings_dates(&self, py: Python<'_>, limit: usize) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fetch_earnings_dates(self.yahoo(), self.symbol.clone(), limit),
+        )
+    }
+
+    /// Get calendar events as a dictionary
+    #[getter]
+    fn calendar(&self, py: Python<'_>) -> PyResult<Calendar> {
+        self.get_calendar(py, false)
+    }
+
+    /// Get calendar events as a dictionary, or as a one-row Polars DataFrame with `as_frame=True`
+    #[pyo3(signature = (as_frame=false))]
+    fn get_calendar(&self, py: Python<'_>, as_frame: bool) -> PyResult<Calendar> {
+        block_on(
+            py,
+            fetch_calendar(self.yahoo(), self.symbol.clone(), as_frame),
+        )
+    }
+
+    // ============ Phase 3: Analysis & Holders ============
+
+    /// Get analyst recommendations as a Polars DataFrame
+    #[getter]
+    fn recommendations(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_recommendations(self.yf_ticker()))
+    }
+
+    /// Get analyst upgrades/downgrades history as a Polars DataFrame
+    #[getter]
+    fn upgrades_downgrades(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_upgrades_downgrades(self.yf_ticker()))
+    }
+
+    /// Get analyst price targets (current, high, low, mean, median) as a dictionary
+    #[getter]
+    fn analyst_price_targets(&self, py: Python<'_>) -> PyResult<Record> {
+        block_on(
+            py,
+            fetch_analyst_price_targets(self.yahoo(), self.symbol.clone()),
+        )
+    }
+
+    /// Get strong buy/buy/hold/sell/strong sell counts per month as a Polars DataFrame
+    #[getter]
+    fn recommendations_summary(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fetch_recommendations_summary(self.yahoo(), self.symbol.clone()),
+        )
+    }
+
+    /// Get EPS estimates (avg, low, high, year-ago EPS, analyst count, growth) per period as a Polars DataFrame
+    #[getter]
+    fn earnings_estimate(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fetch_earnings_trend(
+                self.yahoo(),
+                self.symbol.clone(),
+                "earningsEstimate",
+                EARNINGS_ESTIMATE_FIELDS,
+            ),
+        )
+    }
+
+    /// Get revenue estimates (avg, low, high, analyst count, year-ago revenue, growth) per period as a Polars DataFrame
+    #[getter]
+    fn revenue_estimate(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fetch_earnings_trend(
+                self.yahoo(),
+                self.symbol.clone(),
+                "revenueEstimate",
+                REVENUE_ESTIMATE_FIELDS,
+            ),
+        )
+    }
+
+    /// Get the consensus EPS estimate now and 7, 30, 60 and 90 days ago per period as a Polars DataFrame
+    #[getter]
+    fn eps_trend(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fetch_earnings_trend(
+                self.yahoo(),
+                self.symbol.clone(),
+                "epsTrend",
+                EPS_TREND_FIELDS,
+            ),
+        )
+    }
+
+    /// Get the number of upward and downward EPS revisions over the last 7 and 30 days per period as a Polars DataFrame
+    #[getter]
+    fn eps_revisions(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fetch_earnings_trend(
+                self.yahoo(),
+                self.symbol.clone(),
+                "epsRevisions",
+                EPS_REVISIONS_FIELDS,
+            ),
+        )
+    }
+
+    /// Get expected growth of the stock and its index per period as a Polars DataFrame
+    #[getter]
+    fn growth_estimates(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(
+            py,
+            fetch_growth_estimates(self.yahoo(), self.symbol.clone()),
+        )
+    }
+
+    /// Get major holders breakdown as a Polars DataFrame
+    #[getter]
+    fn major_holders(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_major_holders(self.yf_ticker()))
+    }
+
+    /// Get institutional holders as a Polars DataFrame
+    #[getter]
+    fn institutional_holders(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_institutional_holders(self.yf_ticker()))
+    }
+
+    /// Get mutual fund holders as a Polars DataFrame
+    #[getter]
+    fn mutualfund_holders(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_mutualfund_holders(self.yf_ticker()))
+    }
+
+    /// Get insider transactions as a Polars DataFrame
+    #[getter]
+    fn insider_transactions(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_insider_transactions(self.yf_ticker()))
+    }
+
+    /// Get insider roster holders as a Polars DataFrame
+    #[getter]
+    fn insider_roster_holders(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        block_on(py, fetch_insider_roster_holders(self.yf_ticker()))
+    }
+
+    // ============ Phase 4: Options ============
+
+    /// Get available option expiration dates as a list of strings (YYYY-MM-DD)
+    #[getter]
+    fn options(&self, py: Python<'_>) -> PyResult<Vec<String>> {
+        block_on(py, fetch_options(self.yf_ticker()))
+    }
+
+    /// Get option chain for a specific expiration date
+    ///
+    /// Args:
+    ///     date: Expiration date in YYYY-MM-DD format (optional, uses nearest if not provided)
+    ///
+    /// Returns:
+    ///     tuple: (calls DataFrame, puts DataFrame)
+    #[pyo3(signature = (date=None))]
+    fn option_chain(
+        &self,
+        py: Python<'_>,
+        date: Option<&str>,
+    ) -> PyResult<(PyDataFrame, PyDataFrame)> {
+        let timestamp = parse_expiration(date)?;
+        block_on(py, fetch_option_chain(self.yf_ticker(), timestamp))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("yfinance.Ticker('{}')", self.symbol)
+    }
+}
+
+/// Asyncio variant of Ticker: every method returns an awaitable
+#[pyclass]
+struct AsyncTicker {
+    symbol: String,
+    client: Client,
+}
+
+impl AsyncTicker {
+    fn yf_ticker(&self) -> YfTicker {
+        YfTicker::new(&self.client.yf, &self.symbol)
+    }
+
+    fn yahoo(&self) -> Yahoo {
+        self.client.yahoo.clone()
+    }
+}
+
+#[pymethods]
+impl AsyncTicker {
+    #[new]
+    #[pyo3(signature = (symbol, session=None))]
+    fn new(symbol: String, session: Option<PyRef<'_, Client>>) -> PyResult<Self> {
+        Ok(AsyncTicker {
+            symbol,
+            client: session_client(session)?,
+        })
+    }
+
+    /// Get historical OHLCV data as a Polars DataFrame (awaitable)
+    ///
+    /// Arguments are validated eagerly, so invalid parameters raise before awaiting.
+    /// See Ticker.history for the meaning of each argument.
+    #[pyo3(signature = (period=None, interval=None, start=None, end=None, prepost=false, auto_adjust=true, actions=true))]
+    #[allow(clippy::too_many_arguments)]
+    fn history<'py>(
+        &self,
+        py: Python<'py>,
+        period: Option<&str>,
+        interval: Option<&str>,
+        start: Option<DateArg>,
+        end: Option<DateArg>,
+        prepost: bool,
+        auto_adjust: bool,
+        actions: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let req = HistoryRequest::new(period, interval, start, end, prepost, auto_adjust, actions)?;
+        awaitable(
+            py,
+            fetch_history(self.yf_ticker(), self.yahoo(), self.symbol.clone(), req),
+        )
+    }
+
+    /// Get ticker info as a dictionary (awaitable)
+    ///
+    /// Args:
+    ///     decimal: Return every number as a decimal.Decimal of the exact value Yahoo sent
+    #[pyo3(signature = (decimal=false))]
+    fn info<'py>(&self, py: Python<'py>, decimal: bool) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_info(self.yahoo(), self.symbol.clone(), decimal))
+    }
+
+    /// Get quick-access price metrics with every value already loaded (awaitable)
+    fn fast_info<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_fast_info(self.client.clone(), self.symbol.clone()),
+        )
+    }
+
+    /// Get dividend history as a Polars DataFrame (awaitable)
+    fn dividends<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_dividends(self.yf_ticker()))
+    }
+
+    /// Get stock splits history as a Polars DataFrame (awaitable)
+    fn splits<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_splits(self.yf_ticker()))
+    }
+
+    /// Get corporate actions (dividends, splits and capital gains) as a Polars DataFrame (awaitable)
+    fn actions<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_actions(self.yf_ticker()))
+    }
+
+    /// Get capital gains as a Polars DataFrame (awaitable)
+    fn capital_gains<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_capital_gains(self.yf_ticker()))
+    }
+
+    /// Get the ISIN for this ticker (awaitable)
+    fn get_isin<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_isin(self.yf_ticker()))
+    }
+
+    /// Get annual income statement as a Polars DataFrame (awaitable)
+    fn income_stmt<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_income_stmt(self.yf_ticker()))
+    }
+
+    /// Get quarterly income statement as a Polars DataFrame (awaitable)
+    fn quarterly_income_stmt<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_quarterly_income_stmt(self.yf_ticker()))
+    }
+
+    /// Get annual balance sheet as a Polars DataFrame (awaitable)
+    fn balance_sheet<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_balance_sheet(self.yf_ticker()))
+    }
+
+    /// Get quarterly balance sheet as a Polars DataFrame (awaitable)
+    fn quarterly_balance_sheet<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_quarterly_balance_sheet(self.yf_ticker()))
+    }
+
+    /// Get annual cash flow statement as a Polars DataFrame (awaitable)
+    fn cashflow<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_cashflow(self.yf_ticker()))
+    }
+
+    /// Get quarterly cash flow statement as a Polars DataFrame (awaitable)
+    fn quarterly_cashflow<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_quarterly_cashflow(self.yf_ticker()))
+    }
+
+    /// Get the trailing twelve months income statement as a Polars DataFrame (awaitable)
+    fn ttm_income_stmt<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::Income,
+                Freq::Trailing,
+                Layout::Wide,
+            ),
+        )
+    }
+
+    /// Get the trailing twelve months cash flow statement as a Polars DataFrame (awaitable)
+    fn ttm_cashflow<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::CashFlow,
+                Freq::Trailing,
+                Layout::Wide,
+            ),
+        )
+    }
+
+    /// Get the income statement with yfinance line-item names as a Polars DataFrame (awaitable)
+    #[pyo3(signature = (freq="yearly", layout="wide"))]
+    fn get_income_stmt<'py>(
+        &self,
+        py: Python<'py>,
+        freq: &str,
+        layout: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (freq, layout) = (Freq::parse(freq)?, Layout::parse(layout)?);
+        awaitable(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::Income,
+                freq,
+                layout,
+            ),
+        )
+    }
+
+    /// Get the balance sheet with yfinance line-item names as a Polars DataFrame (awaitable)
+    #[pyo3(signature = (freq="yearly", layout="wide"))]
+    fn get_balance_sheet<'py>(
+        &self,
+        py: Python<'py>,
+        freq: &str,
+        layout: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (freq, layout) = (Freq::parse(freq)?, Layout::parse(layout)?);
+        awaitable(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::BalanceSheet,
+                freq,
+                layout,
+            ),
+        )
+    }
+
+    /// Get the cash flow statement with yfinance line-item names as a Polars DataFrame (awaitable)
+    #[pyo3(signature = (freq="yearly", layout="wide"))]
+    fn get_cashflow<'py>(
+        &self,
+        py: Python<'py>,
+        freq: &str,
+        layout: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (freq, layout) = (Freq::parse(freq)?, Layout::parse(layout)?);
+        awaitable(
+            py,
+            fundamentals::fetch_statement(
+                self.yahoo(),
+                self.symbol.clone(),
+                Statement::CashFlow,
+                freq,
+                layout,
+            ),
+        )
+    }
+
+    /// Get fundamentals timeseries as a long Polars DataFrame (date, key, value, currency) (awaitable)
+    #[pyo3(signature = (keys=None, freq="annual", start=None, end=None))]
+    fn get_fundamentals<'py>(
+        &self,
+        py: Python<'py>,
+        keys: Option<Vec<String>>,
+        freq: &str,
+        start: Option<DateArg>,
+        end: Option<DateArg>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let freq = Freq::parse(freq)?;
+        let (start, end) = timestamp_range(start, end)?;
+        awaitable(
+            py,
+            fundamentals::fetch_fundamentals(
+                self.yahoo(),
+                self.symbol.clone(),
+                keys,
+                freq,
+                start,
+                end,
+            ),
+        )
+    }
+
+    /// Get financial ratios per report date as a Polars DataFrame (awaitable)
+    #[pyo3(signature = (freq="yearly"))]
+    fn ratios<'py>(&self, py: Python<'py>, freq: &str) -> PyResult<Bound<'py, PyAny>> {
+        let freq = Freq::parse(freq)?;
+        awaitable(
+            py,
+            ratios::fetch_ratios(self.yahoo(), self.symbol.clone(), freq),
+        )
+    }
+
+    /// Get the history of shares outstanding as a Polars DataFrame (date, shares) (awaitable)
+    #[pyo3(signature = (start=None, end=None))]
+    fn get_shares_full<'py>(
+        &self,
+        py: Python<'py>,
+        start: Option<DateArg>,
+        end: Option<DateArg>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (start, end) = timestamp_range(start, end)?;
+        awaitable(
+            py,
+            fundamentals::fetch_shares_full(self.yahoo(), self.symbol.clone(), start, end),
+        )
+    }
+
+    /// Get yearly revenue and earnings as a Polars DataFrame (awaitable)
+    fn earnings<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_earnings(self.yahoo(), self.symbol.clone(), false))
+    }
+
+    /// Get quarterly revenue and earnings as a Polars DataFrame (awaitable)
+    fn quarterly_earnings<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_earnings(self.yahoo(), self.symbol.clone(), true))
+    }
+
+    /// Get reported vs estimated EPS for recent quarters as a Polars DataFrame (awaitable)
+    fn earnings_history<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_earnings_history(self.yahoo(), self.symbol.clone()),
+        )
+    }
+
+    /// Get past and upcoming earnings dates as a Polars DataFrame (awaitable)
+    fn earnings_dates<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.get_earnings_dates(py, 12)
+    }
+
+    /// Get up to `limit` past and upcoming earnings dates, newest first (awaitable)
+    #[pyo3(signature = (limit=12))]
+    fn get_earnings_dates<'py>(
+        &self,
+        py: Python<'py>,
+        limit: usize,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_earnings_dates(self.yahoo(), self.symbol.clone(), limit),
+        )
+    }
+
+    /// Get calendar events as a dictionary (awaitable)
+    fn calendar<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.get_calendar(py, false)
+    }
+
+    /// Get calendar events as a dictionary, or a one-row Polars DataFrame with `as_frame=True` (awaitable)
+    #[pyo3(signature = (as_frame=false))]
+    fn get_calendar<'py>(&self, py: Python<'py>, as_frame: bool) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_calendar(self.yahoo(), self.symbol.clone(), as_frame),
+        )
+    }
+
+    /// Get analyst recommendations as a Polars DataFrame (awaitable)
+    fn recommendations<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_recommendations(self.yf_ticker()))
+    }
+
+    /// Get analyst upgrades/downgrades history as a Polars DataFrame (awaitable)
+    fn upgrades_downgrades<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_upgrades_downgrades(self.yf_ticker()))
+    }
+
+    /// Get analyst price targets (current, high, low, mean, median) as a dictionary (awaitable)
+    fn analyst_price_targets<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_analyst_price_targets(self.yahoo(), self.symbol.clone()),
+        )
+    }
+
+    /// Get strong buy/buy/hold/sell/strong sell counts per month as a Polars DataFrame (awaitable)
+    fn recommendations_summary<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_recommendations_summary(self.yahoo(), self.symbol.clone()),
+        )
+    }
+
+    /// Get EPS estimates (avg, low, high, year-ago EPS, analyst count, growth) per period as a Polars DataFrame (awaitable)
+    fn earnings_estimate<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_earnings_trend(
+                self.yahoo(),
+                self.symbol.clone(),
+                "earningsEstimate",
+                EARNINGS_ESTIMATE_FIELDS,
+            ),
+        )
+    }
+
+    /// Get revenue estimates (avg, low, high, analyst count, year-ago revenue, growth) per period as a Polars DataFrame (awaitable)
+    fn revenue_estimate<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_earnings_trend(
+                self.yahoo(),
+                self.symbol.clone(),
+                "revenueEstimate",
+                REVENUE_ESTIMATE_FIELDS,
+            ),
+        )
+    }
+
+    /// Get the consensus EPS estimate now and 7, 30, 60 and 90 days ago per period as a Polars DataFrame (awaitable)
+    fn eps_trend<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_earnings_trend(
+                self.yahoo(),
+                self.symbol.clone(),
+                "epsTrend",
+                EPS_TREND_FIELDS,
+            ),
+        )
+    }
+
+    /// Get the number of upward and downward EPS revisions over the last 7 and 30 days per period as a Polars DataFrame (awaitable)
+    fn eps_revisions<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_earnings_trend(
+                self.yahoo(),
+                self.symbol.clone(),
+                "epsRevisions",
+                EPS_REVISIONS_FIELDS,
+            ),
+        )
+    }
+
+    /// Get expected growth of the stock and its index per period as a Polars DataFrame (awaitable)
+    fn growth_estimates<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(
+            py,
+            fetch_growth_estimates(self.yahoo(), self.symbol.clone()),
+        )
+    }
+
+    /// Get major holders breakdown as a Polars DataFrame (awaitable)
+    fn major_holders<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_major_holders(self.yf_ticker()))
+    }
+
+    /// Get institutional holders as a Polars DataFrame (awaitable)
+    fn institutional_holders<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_institutional_holders(self.yf_ticker()))
+    }
+
+    /// Get mutual fund holders as a Polars DataFrame (awaitable)
+    fn mutualfund_holders<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_mutualfund_holders(self.yf_ticker()))
+    }
+
+    /// Get insider transactions as a Polars DataFrame (awaitable)
+    fn insider_transactions<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_insider_transactions(self.yf_ticker()))
+    }
+
+    /// Get insider roster holders as a Polars DataFrame (awaitable)
+    fn insider_roster_holders<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_insider_roster_holders(self.yf_ticker()))
+    }
+
+    /// Get available option expiration dates as a list of strings (awaitable)
+    fn options<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        awaitable(py, fetch_options(self.yf_ticker()))
+    }
+
+    /// Get option chain for a specific expiration date (awaitable)
+    ///
+    /// Resolves to a tuple of (calls DataFrame, puts DataFrame).
+    #[pyo3(signature = (date=None))]
+    fn option_chain<'py>(
+        &self,
+        py: Python<'py>,
+        date: Option<&str>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let timestamp = parse_expiration(date)?;
+        awaitable(py, fetch_option_chain(self.yf_ticker(), timestamp))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("yfinance.AsyncTicker('{}')", self.symbol)
+    }
+}
+
+// ============ Multiple tickers (download / Tickers) ============
+
+const DEFAULT_DOWNLOAD_THREADS: usize = 8;
+
+/// Symbols given as one string ("AAPL MSFT" or "AAPL,MSFT") or a list
+#[derive(FromPyObject)]
+enum TickersArg {
+    Str(String),
+    List(Vec<String>),
+}
+
+impl TickersArg {
+    /// Normalise to a de-duplicated, upper-cased list preserving input order
+    fn into_symbols(self) -> PyResult<Vec<String>> {
+        let raw: Vec<String> = match self {
+            TickersArg::Str(s) => s
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .map(str::to_string)
+                .collect(),
+            TickersArg::List(list) => list,
+        };
+
+        let mut symbols: Vec<String> = Vec::with_capacity(raw.len());
+        for symbol in raw {
+            let symbol = symbol.trim().to_uppercase();
+            if !symbol.is_empty() && !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+
+        if symbols.is_empty() {
+            return Err(InvalidParameterError::new_err("No tickers given"));
+        }
+        Ok(symbols)
+    }
+}
+
+/// Run `fetch` for every symbol with at most `threads` requests in flight.
+/// Results keep the input order; failures are returned alongside successes.
+async fn fetch_many<F, Fut, T>(
+    client: Client,
+    symbols: Vec<String>,
+    threads: usize,
+    fetch: F,
+) -> Vec<(String, PyResult<T>)>
+where
+    F: Fn(YfTicker, String) -> Fut,
+    Fut: Future<Output = PyResult<T>>,
+{
+    stream::iter(symbols.into_iter().map(|symbol| {
+        let fut = fetch(YfTicker::new(&client.yf, &symbol), symbol.clone());
+        async move { (symbol, fut.await) }
+    }))
+    .buffered(threads.max(1))
+    .collect()
+    .await
+}
+
+/// Keep successful results, warning about failed symbols (or raising if every symbol failed)
+fn partition_results<T>(
+    py: Python<'_>,
+    results: Vec<(String, PyResult<T>)>,
+) -> PyResult<Vec<(String, T)>> {
+    let mut ok = Vec::with_capacity(results.len());
+    let mut failures = Vec::new();
+    for (symbol, result) in results {
+        match result {
+            Ok(value) => ok.push((symbol, value)),
+            Err(e) => failures.push((symbol, e)),
+        }
+    }
+
+    if ok.is_empty() && !failures.is_empty() {
+        // A single symbol keeps its specific error class (e.g. TickerNotFoundError)
+        if let [(_, e)] = failures.as_slice() {
+            return Err(e.clone_ref(py));
+        }
+        let messages: Vec<String> = failures
+            .iter()
+            .map(|(symbol, e)| format!("{}: {}", symbol, e))
+            .collect();
+        return Err(to_py_err(format!(
+            "All downloads failed: {}",
+            messages.join("; ")
+        )));
+    }
+    if !failures.is_empty() {
+        let messages: Vec<String> = failures
+            .iter()
+            .map(|(symbol, e)| format!("{}: {}", symbol, e))
+            .collect();
+        let message = format!(
+            "{} failed download(s): {}",
+            failures.len(),
+            messages.join("; ")
+        );
+        let message = CString::new(message).map_err(to_py_err)?;
+        PyErr::warn(py, &py.get_type::<PyUserWarning>(), &message, 1)?;
+    }
+    Ok(ok)
+}
+
+/// Stack per-ticker frames into one long frame with a leading `ticker` column
+fn concat_long(frames: Vec<(String, DataFrame)>) -> PolarsResult<DataFrame> {
+    let frames = frames
+        .into_iter()
+        .map(|(symbol, mut df)| {
+            let tickers = Series::new("ticker".into(), vec![symbol; df.height()]);
+            df.insert_column(0, tickers)?;
+            Ok(df)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    concat_df_diagonal(&frames)
+}
+
+/// Join per-ticker frames on `date` into one wide frame.
+///
+/// Columns are named `{ticker}_{field}` grouped by ticker, or `{field}_{ticker}` grouped by field.
+fn concat_wide(frames: Vec<(String, DataFrame)>, by_ticker: bool) -> PolarsResult<DataFrame> {
+    let mut fields: Vec<String> = Vec::new();
+    let mut joined: Option<LazyFrame> = None;
+
+    for (symbol, df) in &frames {
+        let mut exprs = vec![col("date")];
+        for name in df.get_column_names() {
+            if name.as_str() == "date" {
+                continue;
+            }
+            if !fields.iter().any(|f| f == name.as_str()) {
+                fields.push(name.to_string());
+            }
+            let alias = if by_ticker {
+                format!("{}_{}", symbol, name)
+            } else {
+                format!("{}_{}", name, symbol)
+            };
+            exprs.push(col(name.as_str()).alias(alias));
+        }
+
+        let lf = df.clone().lazy().select(exprs);
+        joined = Some(match joined {
+            None => lf,
+            Some(acc) => acc.join(
+                lf,
+                [col("date")],
+                [col("date")],
+                JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns),
+            ),
+        });
+    }
+
+    let Some(joined) = joined else {
+        return Ok(DataFrame::empty());
+    };
+    let df = joined.sort(["date"], Default::default()).collect()?;
+    if by_ticker {
+        return Ok(df);
+    }
+
+    let mut order = vec!["date".to_string()];
+    for field in &fields {
+        for (symbol, _) in &frames {
+            let name = format!("{}_{}", field, symbol);
+            if df.column(&name).is_ok() {
+                order.push(name);
+            }
+        }
+    }
+    df.select(order)
 }
 
-fn to_py_err<E: std::fmt::Display>(e: E) -> PyErr {
-    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string())
+/// Map `group_by` to the wide layout flag (None means the long layout)
+fn parse_group_by(group_by: Option<&str>) -> PyResult<Option<bool>> {
+    match group_by {
+        None => Ok(None),
+        Some("ticker") => Ok(Some(true)),
+        Some("column") => Ok(Some(false)),
+        Some(other) => Err(invalid_choice("group_by", other, &["ticker", "column"])),
+    }
 }
 
-/// Convert 'ts' column (i64 milliseconds) to 'date' column (Datetime type)
-fn convert_ts_to_date(df: DataFrame) -> PolarsResult<DataFrame> {
-    if let Ok(ts_col) = df.column("ts") {
-        // Cast ts column to Datetime
-        let date_col = ts_col
-            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
-            .with_name("date".into());
+/// Fetch history for several symbols and combine it into one long or wide frame
+fn history_many(
+    py: Python<'_>,
+    client: Client,
+    symbols: Vec<String>,
+    req: HistoryRequest,
+    threads: usize,
+    wide_by_ticker: Option<bool>,
+) -> PyResult<PyDataFrame> {
+    let yahoo = client.yahoo.clone();
+    let results = block_on(py, async move {
+        let results = fetch_many(client, symbols, threads, |ticker, symbol| {
+            fetch_history(ticker, yahoo.clone(), symbol, req.clone())
+        })
+        .await;
+        Ok(results)
+    })?;
+    let frames = partition_results(py, results)?
+        .into_iter()
+        .map(|(symbol, df)| (symbol, df.0))
+        .collect();
 
-        // Drop ts column and add date column
-        let mut df = df.drop("ts")?;
-        df.with_column(date_col)?;
-        Ok(df)
-    } else {
-        Ok(df)
+    let df = match wide_by_ticker {
+        None => concat_long(frames),
+        Some(by_ticker) => concat_wide(frames, by_ticker),
     }
+    .map_err(to_py_err)?;
+    Ok(PyDataFrame(df))
 }
 
-/// Convert i64 timestamps (seconds) to a Datetime Series
-fn timestamps_to_datetime_series_sec(timestamps: &[i64], name: &str) -> Series {
-    // Convert seconds to milliseconds
-    let timestamps_ms: Vec<i64> = timestamps.iter().map(|ts| ts * 1000).collect();
-    Series::new(name.into(), timestamps_ms)
-        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
-        .unwrap()
+/// Collection of tickers whose properties are fetched concurrently
+#[pyclass]
+struct Tickers {
+    symbols: Vec<String>,
+    client: Client,
+    threads: usize,
 }
 
-fn parse_range(period: &str) -> Range {
-    match period {
-        "1d" => Range::D1,
-        "5d" => Range::D5,
-        "1mo" => Range::M1,
-        "3mo" => Range::M3,
-        "6mo" => Range::M6,
-        "1y" => Range::Y1,
-        "2y" => Range::Y2,
-        "5y" => Range::Y5,
-        "10y" => Range::Y10,
-        "ytd" => Range::Ytd,
-        "max" => Range::Max,
-        _ => Range::M1,
-    }
-}
-
-fn parse_interval(interval: &str) -> Interval {
-    match interval {
-        "1m" => Interval::I1m,
-        "2m" => Interval::I2m,
-        "5m" => Interval::I5m,
-        "15m" => Interval::I15m,
-        "30m" => Interval::I30m,
-        "90m" => Interval::I90m,
-        "60m" | "1h" => Interval::I1h,
-        "1d" => Interval::D1,
-        "5d" => Interval::D5,
-        "1wk" => Interval::W1,
-        "1mo" => Interval::M1,
-        "3mo" => Interval::M3,
-        _ => Interval::D1,
+impl Tickers {
+    /// Fetch one frame per symbol and stack them with a `ticker` column
+    fn frames<F, Fut>(&self, py: Python<'_>, fetch: F) -> PyResult<PyDataFrame>
+    where
+        F: Fn(YfTicker) -> Fut + Send + Sync,
+        Fut: Future<Output = PyResult<PyDataFrame>> + Send,
+    {
+        let (client, symbols, threads) = (self.client.clone(), self.symbols.clone(), self.threads);
+        let results = block_on(py, async move {
+            Ok(fetch_many(client, symbols, threads, |ticker, _| fetch(ticker)).await)
+        })?;
+        let frames = partition_results(py, results)?
+            .into_iter()
+            .map(|(symbol, df)| (symbol, df.0))
+            .collect();
+        Ok(PyDataFrame(concat_long(frames).map_err(to_py_err)?))
     }
-}
 
-/// Ticker class for fetching stock data (yfinance-compatible API)
-#[pyclass]
-struct Ticker {
-    symbol: String,
+    /// Fetch one dict per symbol and return them keyed by symbol
+    fn records<'py, F, Fut>(&self, py: Python<'py>, fetch: F) -> PyResult<Bound<'py, PyDict>>
+    where
+        F: Fn(YfTicker, String) -> Fut + Send + Sync,
+        Fut: Future<Output = PyResult<Record>> + Send,
+    {
+        let (client, symbols, threads) = (self.client.clone(), self.symbols.clone(), self.threads);
+        let results = block_on(py, async move {
+            Ok(fetch_many(client, symbols, threads, fetch).await)
+        })?;
+
+        let dict = PyDict::new(py);
+        for (symbol, record) in partition_results(py, results)? {
+            dict.set_item(symbol, record)?;
+        }
+        Ok(dict)
+    }
 }
 
 #[pymethods]
-impl Ticker {
+impl Tickers {
+    /// Args:
+    ///     tickers: Symbols as a list or a space/comma separated string
+    ///     threads: Maximum number of concurrent requests (default 8)
+    ///     session: Client to use instead of the shared default
     #[new]
-    fn new(symbol: String) -> Self {
-        Ticker { symbol }
+    #[pyo3(signature = (tickers, threads=None, session=None))]
+    fn new(
+        tickers: TickersArg,
+        threads: Option<usize>,
+        session: Option<PyRef<'_, Client>>,
+    ) -> PyResult<Self> {
+        Ok(Tickers {
+            symbols: tickers.into_symbols()?,
+            client: session_client(session)?,
+            threads: threads.unwrap_or(DEFAULT_DOWNLOAD_THREADS),
+        })
     }
 
-    /// Get historical OHLCV data as a Polars DataFrame
+    /// Symbols in this collection
+    #[getter]
+    fn symbols(&self) -> Vec<String> {
+        self.symbols.clone()
+    }
+
+    /// Per-symbol Ticker objects sharing this collection's client
+    #[getter]
+    fn tickers<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for symbol in &self.symbols {
+            let ticker = Ticker {
+                symbol: symbol.clone(),
+                client: self.client.clone(),
+                fast_info: OnceLock::new(),
+            };
+            dict.set_item(symbol, Py::new(py, ticker)?)?;
+        }
+        Ok(dict)
+    }
+
+    /// Get historical OHLCV data for all symbols as one Polars DataFrame
     ///
-    /// Args:
-    ///     period: Data period (1d, 5d, 1mo, 3mo, 6mo, 1y, 2y, 5y, 10y, ytd, max)
-    ///     interval: Data interval (1m, 2m, 5m, 15m, 30m, 60m, 90m, 1h, 1d, 5d, 1wk, 1mo, 3mo)
-    ///     start: Start date (not yet implemented)
-    ///     end: End date (not yet implemented)
-    ///     prepost: Include pre and post market data
-    ///     auto_adjust: Adjust prices for splits and dividends
-    ///     actions: Include dividends and stock splits
-    #[pyo3(signature = (period=None, interval=None, start=None, end=None, prepost=false, auto_adjust=true, actions=true))]
+    /// Takes the same arguments as download() (except tickers and threads).
+    #[pyo3(signature = (period=None, interval=None, start=None, end=None, group_by=None, prepost=false, auto_adjust=true, actions=true))]
+    #[allow(clippy::too_many_arguments)]
     fn history(
         &self,
+        py: Python<'_>,
         period: Option<&str>,
         interval: Option<&str>,
-        start: Option<&str>,
-        end: Option<&str>,
+        start: Option<DateArg>,
+        end: Option<DateArg>,
+        group_by: Option<&str>,
         prepost: bool,
         auto_adjust: bool,
         actions: bool,
     ) -> PyResult<PyDataFrame> {
-        let _ = (start, end); // TODO: implement start/end date filtering
-
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
-
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-
-            let range_val = parse_range(period.unwrap_or("1mo"));
-            let interval_val = parse_interval(interval.unwrap_or("1d"));
-
-            let mut builder = ticker.history_builder();
-            builder = builder
-                .range(range_val)
-                .interval(interval_val)
-                .auto_adjust(auto_adjust)
-                .prepost(prepost)
-                .actions(actions);
-
-            let candles = builder.fetch().await.map_err(to_py_err)?;
-            let df = candles.to_dataframe().map_err(to_py_err)?;
-            let df = convert_ts_to_date(df).map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+        let wide_by_ticker = parse_group_by(group_by)?;
+        let req = HistoryRequest::new(period, interval, start, end, prepost, auto_adjust, actions)?;
+        history_many(
+            py,
+            self.client.clone(),
+            self.symbols.clone(),
+            req,
+            self.threads,
+            wide_by_ticker,
+        )
     }
 
-    /// Get ticker info as a dictionary
+    /// Get ticker info for all symbols as a dictionary keyed by symbol
     #[getter]
-    fn info(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
-
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let info = ticker.info().await.map_err(to_py_err)?;
+    fn info<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let yahoo = self.client.yahoo.clone();
+        self.records(py, |_, symbol| fetch_info(yahoo.clone(), symbol, false))
+    }
 
-            let dict = PyDict::new(py);
+    /// Get lazily loaded FastInfo objects for all symbols, keyed by symbol
+    #[getter]
+    fn fast_info<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for symbol in &self.symbols {
+            let fast_info = FastInfo::new(symbol.clone(), self.client.clone());
+            dict.set_item(symbol, Py::new(py, fast_info)?)?;
+        }
+        Ok(dict)
+    }
 
-            // Basic info
-            dict.set_item("symbol", &symbol)?;
+    /// Get dividend history for all symbols as a Polars DataFrame
+    #[getter]
+    fn dividends(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_dividends)
+    }
 
-            // Name
-            if let Some(name) = &info.name {
-                dict.set_item("shortName", name)?;
-            }
+    /// Get stock splits history for all symbols as a Polars DataFrame
+    #[getter]
+    fn splits(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_splits)
+    }
 
-            // ISIN
-            if let Some(isin) = &info.isin {
-                dict.set_item("isin", isin.to_string())?;
-            }
+    /// Get corporate actions for all symbols as a Polars DataFrame
+    #[getter]
+    fn actions(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_actions)
+    }
 
-            // Exchange
-            if let Some(exchange) = &info.exchange {
-                dict.set_item("exchange", format!("{:?}", exchange))?;
-            }
+    /// Get capital gains for all symbols as a Polars DataFrame
+    #[getter]
+    fn capital_gains(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_capital_gains)
+    }
 
-            // Market state
-            if let Some(state) = &info.market_state {
-                dict.set_item("marketState", format!("{:?}", state))?;
-            }
+    /// Get annual income statements for all symbols as a Polars DataFrame
+    #[getter]
+    fn income_stmt(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_income_stmt)
+    }
 
-            // Currency
-            if let Some(currency) = &info.currency {
-                dict.set_item("currency", format!("{:?}", currency))?;
-            }
+    /// Get quarterly income statements for all symbols as a Polars DataFrame
+    #[getter]
+    fn quarterly_income_stmt(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_quarterly_income_stmt)
+    }
 
-            // Prices
-            if let Some(last) = &info.last {
-                dict.set_item("regularMarketPrice", last.amount().to_string())?;
-            }
-            if let Some(open) = &info.open {
-                dict.set_item("regularMarketOpen", open.amount().to_string())?;
-            }
-            if let Some(high) = &info.high {
-                dict.set_item("regularMarketDayHigh", high.amount().to_string())?;
-            }
-            if let Some(low) = &info.low {
-                dict.set_item("regularMarketDayLow", low.amount().to_string())?;
-            }
-            if let Some(prev) = &info.previous_close {
-                dict.set_item("regularMarketPreviousClose", prev.amount().to_string())?;
-            }
+    /// Get annual balance sheets for all symbols as a Polars DataFrame
+    #[getter]
+    fn balance_sheet(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_balance_sheet)
+    }
 
-            // Volume
-            if let Some(vol) = info.volume {
-                dict.set_item("regularMarketVolume", vol)?;
-            }
-            if let Some(avg_vol) = info.average_volume {
-                dict.set_item("averageVolume", avg_vol)?;
-            }
+    /// Get quarterly balance sheets for all symbols as a Polars DataFrame
+    #[getter]
+    fn quarterly_balance_sheet(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_quarterly_balance_sheet)
+    }
 
-            // Fundamentals
-            if let Some(mc) = &info.market_cap {
-                dict.set_item("marketCap", mc.amount().to_string())?;
-            }
-            if let Some(shares) = info.shares_outstanding {
-                dict.set_item("sharesOutstanding", shares)?;
-            }
-            if let Some(eps) = &info.eps_ttm {
-                dict.set_item("trailingEps", eps.amount().to_string())?;
-            }
-            if let Some(pe) = &info.pe_ttm {
-                dict.set_item("trailingPE", pe.to_string())?;
-            }
-            if let Some(div_yield) = &info.dividend_yield {
-                dict.set_item("dividendYield", div_yield.to_string())?;
-            }
+    /// Get annual cash flow statements for all symbols as a Polars DataFrame
+    #[getter]
+    fn cashflow(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_cashflow)
+    }
 
-            // 52-week range
-            if let Some(low52) = &info.fifty_two_week_low {
-                dict.set_item("fiftyTwoWeekLow", low52.amount().to_string())?;
-            }
-            if let Some(high52) = &info.fifty_two_week_high {
-                dict.set_item("fiftyTwoWeekHigh", high52.amount().to_string())?;
-            }
+    /// Get quarterly cash flow statements for all symbols as a Polars DataFrame
+    #[getter]
+    fn quarterly_cashflow(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_quarterly_cashflow)
+    }
 
-            Ok(dict.into())
-        })
+    /// Get analyst recommendations for all symbols as a Polars DataFrame
+    #[getter]
+    fn recommendations(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_recommendations)
     }
 
-    /// Get fast info as a dictionary
+    /// Get analyst upgrades/downgrades for all symbols as a Polars DataFrame
     #[getter]
-    fn fast_info(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    fn upgrades_downgrades(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.frames(py, fetch_upgrades_downgrades)
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let fast_info = ticker.fast_info().await.map_err(to_py_err)?;
+    fn __repr__(&self) -> String {
+        format!("yfinance.Tickers object <{}>", self.symbols.join(","))
+    }
+}
 
-            let dict = PyDict::new(py);
-            dict.set_item("symbol", &symbol)?;
+/// Download price history for several tickers concurrently
+///
+/// Args:
+///     tickers: Symbols as a list or a space/comma separated string
+///     period, interval, start, end, prepost, auto_adjust, actions: As in Ticker.history
+///     group_by: None for a long frame with a `ticker` column; "ticker" or "column" for a
+///         wide frame with `{ticker}_{field}` or `{field}_{ticker}` columns joined on date
+///     threads: Maximum number of concurrent requests (default 8)
+///     session: Client to use instead of the shared default
+///
+/// Symbols that fail to download are skipped with a warning; an error is raised if all fail.
+#[pyfunction]
+#[pyo3(signature = (tickers, period=None, interval=None, start=None, end=None, group_by=None, threads=None, prepost=false, auto_adjust=true, actions=true, session=None))]
+#[allow(clippy::too_many_arguments)]
+fn download(
+    py: Python<'_>,
+    tickers: TickersArg,
+    period: Option<&str>,
+    interval: Option<&str>,
+    start: Option<DateArg>,
+    end: Option<DateArg>,
+    group_by: Option<&str>,
+    threads: Option<usize>,
+    prepost: bool,
+    auto_adjust: bool,
+    actions: bool,
+    session: Option<PyRef<'_, Client>>,
+) -> PyResult<PyDataFrame> {
+    let symbols = tickers.into_symbols()?;
+    let wide_by_ticker = parse_group_by(group_by)?;
+    let req = HistoryRequest::new(period, interval, start, end, prepost, auto_adjust, actions)?;
+    let threads = threads.unwrap_or(DEFAULT_DOWNLOAD_THREADS);
 
-            if let Some(name) = &fast_info.name {
-                dict.set_item("name", name)?;
-            }
-            if let Some(exchange) = &fast_info.exchange {
-                dict.set_item("exchange", format!("{:?}", exchange))?;
-            }
-            if let Some(currency) = &fast_info.currency {
-                dict.set_item("currency", format!("{:?}", currency))?;
-            }
-            if let Some(volume) = fast_info.volume {
-                dict.set_item("volume", volume)?;
-            }
+    history_many(
+        py,
+        session_client(session)?,
+        symbols,
+        req,
+        threads,
+        wide_by_ticker,
+    )
+}
 
-            Ok(dict.into())
-        })
+/// Python module for yfinance-pl
+#[pymodule]
+fn _yfinance_pl(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Ticker>()?;
+    m.add_class::<AsyncTicker>()?;
+    m.add_class::<Tickers>()?;
+    m.add_class::<Client>()?;
+    m.add_class::<FastInfo>()?;
+    m.add_function(wrap_pyfunction!(download, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
     }
 
-    /// Get dividend history as a Polars DataFrame
-    #[getter]
-    fn dividends(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    fn day(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let divs = ticker.dividends(None).await.map_err(to_py_err)?;
+    #[test]
+    fn market_states_use_yfinance_strings() {
+        let cases = [
+            ("PREPRE", "PREPRE"),
+            ("PRE", "PRE"),
+            ("REGULAR", "REGULAR"),
+            ("POST", "POST"),
+            ("POSTPOST", "POSTPOST"),
+            ("CLOSED", "CLOSED"),
+            ("regular", "REGULAR"),
+            ("PreMarket", "PRE"),
+            ("Open", "REGULAR"),
+            ("post_market", "POST"),
+            ("after-hours", "POST"),
+            ("Closed", "CLOSED"),
+        ];
+        for (state, code) in cases {
+            assert_eq!(market_state_code(state), code, "{}", state);
+        }
+    }
 
-            let timestamps: Vec<i64> = divs.iter().map(|(ts, _)| *ts).collect();
-            let amounts: Vec<f64> = divs.iter().map(|(_, amt)| *amt).collect();
+    #[test]
+    fn currencies_use_iso_codes() {
+        // (Yahoo currency, ISO 4217 code, prices in minor unit)
+        let cases = [
+            ("USD", "USD", false),
+            ("JPY", "JPY", false),
+            ("GBP", "GBP", false),
+            ("eur", "EUR", false),
+            ("GBp", "GBP", true),
+            ("GBX", "GBP", true),
+            ("ZAc", "ZAR", true),
+            ("ILA", "ILS", true),
+        ];
+        for (currency, code, minor_unit) in cases {
+            assert_eq!(
+                currency_code(currency),
+                (code.to_string(), minor_unit),
+                "{}",
+                currency
+            );
+        }
+    }
 
-            let df = DataFrame::new(vec![
-                timestamps_to_datetime_series_sec(&timestamps, "date").into(),
-                Series::new("dividends".into(), amounts).into(),
-            ])
-            .map_err(to_py_err)?;
+    fn endpoint<'a>(endpoints: &'a [(&str, Url)], name: &str) -> Option<&'a str> {
+        endpoints
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, url)| url.as_str())
+    }
 
-            Ok(PyDataFrame(df))
-        })
+    fn info_value(json: &str, decimal: bool) -> Value {
+        let json: serde_json::Value = serde_json::from_str(json).unwrap();
+        json_value(&json, decimal).unwrap()
     }
 
-    /// Get stock splits history as a Polars DataFrame
-    #[getter]
-    fn splits(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn decimal_info_keeps_the_exact_json_number() {
+        let cases = [
+            // More significant digits than an f64 holds
+            ("123456789.123456789012345", "123456789.123456789012345"),
+            ("0.1", "0.1"),
+            ("1e-7", "1e-7"),
+            // Integers such as marketCap follow the decimal mode too
+            ("3012345678901", "3012345678901"),
+            (
+                r#"{"raw": 98765432109876543210.5, "fmt": "98.77T"}"#,
+                "98765432109876543210.5",
+            ),
+        ];
+        for (json, text) in cases {
+            let Value::Decimal(DecimalText(decimal)) = info_value(json, true) else {
+                panic!("{json} is not a Decimal");
+            };
+            assert_eq!(decimal, text, "{json}");
+        }
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let splits = ticker.splits(None).await.map_err(to_py_err)?;
+    #[test]
+    fn float_info_keeps_integers_as_int() {
+        assert!(matches!(
+            info_value("3012345678901", false),
+            Value::Int(3012345678901)
+        ));
+        assert!(matches!(
+            info_value("18446744073709551615", false),
+            Value::UInt(u64::MAX)
+        ));
+        assert!(matches!(info_value("0.25", false), Value::Float(f) if f == 0.25));
+        assert!(
+            matches!(info_value(r#"{"raw": 1.5, "fmt": "1.50"}"#, false), Value::Float(f) if f == 1.5)
+        );
+    }
 
-            let timestamps: Vec<i64> = splits.iter().map(|(ts, _, _)| *ts).collect();
-            let ratios: Vec<f64> = splits
-                .iter()
-                .map(|(_, num, den)| *num as f64 / *den as f64)
-                .collect();
+    /// A history frame with one bar starting at each date
+    fn bars(dates: &[&str]) -> DataFrame {
+        let starts: Vec<i64> = dates
+            .iter()
+            .map(|d| day(d).and_time(NaiveTime::MIN).and_utc().timestamp_millis())
+            .collect();
+        let date = Series::new("date".into(), starts)
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        DataFrame::new(vec![date.into()]).unwrap()
+    }
 
-            let df = DataFrame::new(vec![
-                timestamps_to_datetime_series_sec(&timestamps, "date").into(),
-                Series::new("stock_splits".into(), ratios).into(),
-            ])
-            .map_err(to_py_err)?;
+    fn action(
+        date: &str,
+        dividend: Option<f64>,
+        split: Option<f64>,
+        gain: Option<f64>,
+    ) -> ActionRow {
+        ActionRow {
+            ts: day(date)
+                .and_hms_opt(14, 30, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp(),
+            dividend,
+            split,
+            capital_gain: gain,
+        }
+    }
 
-            Ok(PyDataFrame(df))
-        })
+    fn values(df: &DataFrame, name: &str) -> Vec<f64> {
+        df.column(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
     }
 
-    /// Get corporate actions (dividends + splits) as a Polars DataFrame
-    #[getter]
-    fn actions(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    const WEEKS: &[&str] = &["2024-01-01", "2024-01-08", "2024-01-15"];
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let acts = ticker.actions(None).await.map_err(to_py_err)?;
+    #[test]
+    fn actions_in_one_bar_are_combined() {
+        let mut df = bars(WEEKS);
+        let actions = [
+            action("2024-01-09", Some(0.25), None, None),
+            action("2024-01-11", Some(0.5), None, None),
+            action("2024-01-02", None, Some(2.0), None),
+            action("2024-01-05", None, Some(3.0), None),
+        ];
+        attach_actions(&mut df, &actions).unwrap();
 
-            let mut timestamps: Vec<i64> = Vec::new();
-            let mut dividends: Vec<Option<f64>> = Vec::new();
-            let mut splits: Vec<Option<f64>> = Vec::new();
+        assert_eq!(values(&df, "dividends"), [0.0, 0.75, 0.0]);
+        assert_eq!(values(&df, "stock_splits"), [6.0, 0.0, 0.0]);
+    }
 
-            for action in acts {
-                match action {
-                    yfinance_rs::core::Action::Dividend { ts, amount } => {
-                        timestamps.push(yfinance_rs::core::conversions::datetime_to_i64(ts));
-                        dividends.push(Some(yfinance_rs::core::conversions::money_to_f64(&amount)));
-                        splits.push(None);
-                    }
-                    yfinance_rs::core::Action::Split {
-                        ts,
-                        numerator,
-                        denominator,
-                    } => {
-                        timestamps.push(yfinance_rs::core::conversions::datetime_to_i64(ts));
-                        dividends.push(None);
-                        splits.push(Some(numerator as f64 / denominator as f64));
-                    }
-                    yfinance_rs::core::Action::CapitalGain { .. } => {}
-                }
-            }
+    #[test]
+    fn actions_outside_the_bars_are_dropped() {
+        let mut df = bars(WEEKS);
+        let actions = [
+            // Before the first bar
+            action("2023-12-29", Some(1.0), None, None),
+            // After the last bar, which is taken to be a week long
+            action("2024-01-22", None, Some(2.0), None),
+            action("2024-01-21", Some(0.5), None, None),
+        ];
+        attach_actions(&mut df, &actions).unwrap();
+
+        assert_eq!(values(&df, "dividends"), [0.0, 0.0, 0.5]);
+        assert_eq!(values(&df, "stock_splits"), [0.0, 0.0, 0.0]);
+    }
 
-            let df = DataFrame::new(vec![
-                timestamps_to_datetime_series_sec(&timestamps, "date").into(),
-                Series::new("dividends".into(), dividends).into(),
-                Series::new("stock_splits".into(), splits).into(),
-            ])
-            .map_err(to_py_err)?;
+    #[test]
+    fn capital_gains_column_only_when_present() {
+        let mut df = bars(WEEKS);
+        attach_actions(&mut df, &[]).unwrap();
+        assert_eq!(df.get_column_names(), ["date", "dividends", "stock_splits"]);
+        assert_eq!(values(&df, "dividends"), [0.0, 0.0, 0.0]);
+        assert_eq!(values(&df, "stock_splits"), [0.0, 0.0, 0.0]);
 
-            Ok(PyDataFrame(df))
-        })
+        let mut df = bars(WEEKS);
+        attach_actions(&mut df, &[action("2024-01-16", None, None, Some(1.5))]).unwrap();
+        assert_eq!(values(&df, "capital_gains"), [0.0, 0.0, 1.5]);
     }
 
-    /// Get capital gains as a Polars DataFrame
-    #[getter]
-    fn capital_gains(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    fn symbols(tickers: TickersArg) -> Vec<String> {
+        tickers.into_symbols().unwrap()
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let gains = ticker.capital_gains(None).await.map_err(to_py_err)?;
+    #[test]
+    fn ticker_strings_are_split_and_deduplicated() {
+        let cases = [
+            ("aapl msft", vec!["AAPL", "MSFT"]),
+            ("AAPL, msft,aapl", vec!["AAPL", "MSFT"]),
+            (" spy\tqqq\n", vec!["SPY", "QQQ"]),
+            ("brk-b", vec!["BRK-B"]),
+        ];
+        for (tickers, expected) in cases {
+            assert_eq!(
+                symbols(TickersArg::Str(tickers.to_string())),
+                expected,
+                "{tickers:?}"
+            );
+        }
 
-            let timestamps: Vec<i64> = gains.iter().map(|(ts, _)| *ts).collect();
-            let amounts: Vec<f64> = gains.iter().map(|(_, amt)| *amt).collect();
+        let list = vec![" aapl".to_string(), "AAPL".to_string(), "msft".to_string()];
+        assert_eq!(symbols(TickersArg::List(list)), ["AAPL", "MSFT"]);
 
-            let df = DataFrame::new(vec![
-                timestamps_to_datetime_series_sec(&timestamps, "date").into(),
-                Series::new("capital_gains".into(), amounts).into(),
-            ])
-            .map_err(to_py_err)?;
+        assert!(TickersArg::Str(" , ".to_string()).into_symbols().is_err());
+        assert!(TickersArg::List(Vec::new()).into_symbols().is_err());
+    }
 
-            Ok(PyDataFrame(df))
-        })
+    /// A per-ticker frame with one bar per date and the given float columns
+    fn ticker_frame(dates: &[&str], columns: &[(&str, &[f64])]) -> DataFrame {
+        let mut df = bars(dates);
+        for (name, values) in columns {
+            df.with_column(Series::new((*name).into(), *values))
+                .unwrap();
+        }
+        df
     }
 
-    /// Get the ISIN for this ticker
-    fn get_isin(&self) -> PyResult<Option<String>> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
-
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            ticker.isin().await.map_err(to_py_err)
-        })
+    /// AAPL with close and volume, MSFT with close only, on overlapping dates
+    fn two_tickers() -> Vec<(String, DataFrame)> {
+        vec![
+            (
+                "AAPL".to_string(),
+                ticker_frame(
+                    &["2024-01-02", "2024-01-03"],
+                    &[("close", &[1.0, 2.0]), ("volume", &[10.0, 20.0])],
+                ),
+            ),
+            (
+                "MSFT".to_string(),
+                ticker_frame(&["2024-01-03", "2024-01-04"], &[("close", &[3.0, 4.0])]),
+            ),
+        ]
     }
 
-    // ============ Phase 2: Financial Statements ============
+    fn nullable(df: &DataFrame, name: &str) -> Vec<Option<f64>> {
+        df.column(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
 
-    /// Get annual income statement as a Polars DataFrame
-    #[getter]
-    fn income_stmt(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn long_frames_stack_with_a_ticker_column() {
+        let df = concat_long(two_tickers()).unwrap();
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let stmt = ticker.income_stmt(None).await.map_err(to_py_err)?;
-            let df = stmt.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+        assert_eq!(df.get_column_names(), ["ticker", "date", "close", "volume"]);
+        let tickers: Vec<&str> = df
+            .column("ticker")
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(tickers, ["AAPL", "AAPL", "MSFT", "MSFT"]);
+        // Columns missing for a ticker are filled with nulls
+        assert_eq!(
+            nullable(&df, "volume"),
+            [Some(10.0), Some(20.0), None, None]
+        );
     }
 
-    /// Get quarterly income statement as a Polars DataFrame
-    #[getter]
-    fn quarterly_income_stmt(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn wide_frames_join_on_date_grouped_by_ticker() {
+        let df = concat_wide(two_tickers(), true).unwrap();
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let stmt = ticker.quarterly_income_stmt(None).await.map_err(to_py_err)?;
-            let df = stmt.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+        assert_eq!(
+            df.get_column_names(),
+            ["date", "AAPL_close", "AAPL_volume", "MSFT_close"]
+        );
+        assert_eq!(df.height(), 3);
+        assert_eq!(nullable(&df, "AAPL_close"), [Some(1.0), Some(2.0), None]);
+        assert_eq!(nullable(&df, "MSFT_close"), [None, Some(3.0), Some(4.0)]);
     }
 
-    /// Get annual balance sheet as a Polars DataFrame
-    #[getter]
-    fn balance_sheet(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn wide_frames_grouped_by_column() {
+        let df = concat_wide(two_tickers(), false).unwrap();
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let stmt = ticker.balance_sheet(None).await.map_err(to_py_err)?;
-            let df = stmt.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+        assert_eq!(
+            df.get_column_names(),
+            ["date", "close_AAPL", "close_MSFT", "volume_AAPL"]
+        );
+        assert_eq!(nullable(&df, "volume_AAPL"), [Some(10.0), Some(20.0), None]);
     }
 
-    /// Get quarterly balance sheet as a Polars DataFrame
-    #[getter]
-    fn quarterly_balance_sheet(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn wide_frames_of_nothing_are_empty() {
+        assert_eq!(concat_wide(Vec::new(), true).unwrap().width(), 0);
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let stmt = ticker.quarterly_balance_sheet(None).await.map_err(to_py_err)?;
-            let df = stmt.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+    fn json(text: &str) -> serde_json::Value {
+        serde_json::from_str(text).unwrap()
     }
 
-    /// Get annual cash flow statement as a Polars DataFrame
-    #[getter]
-    fn cashflow(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    fn ints(df: &DataFrame, name: &str) -> Vec<Option<i64>> {
+        df.column(name)
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let stmt = ticker.cashflow(None).await.map_err(to_py_err)?;
-            let df = stmt.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+    fn strs(df: &DataFrame, name: &str) -> Vec<Option<String>> {
+        let column = df.column(name).unwrap().str().unwrap();
+        column.into_iter().map(|s| s.map(str::to_string)).collect()
     }
 
-    /// Get quarterly cash flow statement as a Polars DataFrame
-    #[getter]
-    fn quarterly_cashflow(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    const EARNINGS: &str = r#"{
+        "financialsChart": {
+            "yearly": [
+                {"date": 2023, "revenue": {"raw": 383285000000, "fmt": "383.29B"}, "earnings": {"raw": 96995000000}},
+                {"date": 2024, "revenue": {"raw": 391035000000}}
+            ],
+            "quarterly": [
+                {"date": "3Q2024", "revenue": {"raw": 94930000000}, "earnings": {"raw": 14736000000}}
+            ]
+        }
+    }"#;
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let stmt = ticker.quarterly_cashflow(None).await.map_err(to_py_err)?;
-            let df = stmt.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+    #[test]
+    fn earnings_frames_read_yearly_and_quarterly_rows() {
+        let yearly = earnings_frame(&json(EARNINGS), false).unwrap();
+        assert_eq!(yearly.get_column_names(), ["year", "revenue", "earnings"]);
+        assert_eq!(ints(&yearly, "year"), [Some(2023), Some(2024)]);
+        assert_eq!(nullable(&yearly, "earnings"), [Some(96995000000.0), None]);
+
+        let quarterly = earnings_frame(&json(EARNINGS), true).unwrap();
+        assert_eq!(
+            quarterly.get_column_names(),
+            ["quarter", "revenue", "earnings"]
+        );
+        assert_eq!(strs(&quarterly, "quarter"), [Some("3Q2024".to_string())]);
     }
 
-    /// Get earnings data as a dictionary
-    #[getter]
-    fn earnings(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn earnings_frames_of_a_missing_module_are_empty() {
+        for quarterly in [false, true] {
+            let df = earnings_frame(&serde_json::Value::Null, quarterly).unwrap();
+            assert_eq!(df.height(), 0);
+            assert_eq!(df.width(), 3);
+        }
+        let df = earnings_history_frame(&serde_json::Value::Null).unwrap();
+        assert_eq!(df.height(), 0);
+        assert_eq!(df.width(), 5);
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let earnings = ticker.earnings(None).await.map_err(to_py_err)?;
+    #[test]
+    fn earnings_history_skips_undated_quarters() {
+        let history = json(
+            r#"{"history": [
+                {"quarter": {"raw": 1719705600}, "epsEstimate": {"raw": 1.35}, "epsActual": {"raw": 1.4},
+                 "epsDifference": {"raw": 0.05}, "surprisePercent": {"raw": 0.037}},
+                {"epsEstimate": {"raw": 1.5}},
+                {"quarter": {"raw": 1727654400}, "epsActual": {}}
+            ]}"#,
+        );
+        let df = earnings_history_frame(&history).unwrap();
 
-            let dict = PyDict::new(py);
-            dict.set_item("symbol", &symbol)?;
+        assert_eq!(df.height(), 2);
+        assert_eq!(nullable(&df, "eps_estimate"), [Some(1.35), None]);
+        assert_eq!(nullable(&df, "eps_actual"), [Some(1.4), None]);
+        let quarters = df
+            .column("quarter")
+            .unwrap()
+            .cast(&DataType::Int64)
+            .unwrap();
+        let quarters: Vec<Option<i64>> = quarters.i64().unwrap().into_iter().collect();
+        assert_eq!(quarters, [Some(1719705600000), Some(1727654400000)]);
+    }
 
-            // Add earnings counts
-            dict.set_item("yearly_count", earnings.yearly.len())?;
-            dict.set_item("quarterly_count", earnings.quarterly.len())?;
-            dict.set_item("quarterly_eps_count", earnings.quarterly_eps.len())?;
+    const EARNINGS_DATES: &str = r#"{
+        "columns": [
+            {"id": "ticker"}, {"id": "startdatetime"}, {"id": "epsestimate"},
+            {"id": "epsactual"}, {"id": "epssurprisepct"}
+        ],
+        "rows": [
+            ["AAPL", "2025-01-30T21:30:00.000Z", 2.35, null, null],
+            ["AAPL", 1730406600000, 1.6, 1.64, 2.48],
+            ["AAPL", null, 1.5, 1.53, 1.9],
+            ["AAPL", "2024-05-02T20:30:00Z", 1.5, 1.53, 1.9]
+        ]
+    }"#;
 
-            Ok(dict.into())
-        })
+    fn millis(df: &DataFrame, name: &str) -> Vec<Option<i64>> {
+        let column = df.column(name).unwrap().cast(&DataType::Int64).unwrap();
+        column.i64().unwrap().into_iter().collect()
     }
 
-    /// Get calendar events as a dictionary
-    #[getter]
-    fn calendar(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn earnings_dates_read_iso_and_epoch_times() {
+        let df = earnings_dates_frame(&json(EARNINGS_DATES), Some("America/New_York"), 10).unwrap();
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let cal = ticker.calendar().await.map_err(to_py_err)?;
+        assert_eq!(
+            df.get_column_names(),
+            [
+                "earnings_date",
+                "eps_estimate",
+                "reported_eps",
+                "surprise_percent"
+            ]
+        );
+        // The undated row is skipped
+        assert_eq!(
+            millis(&df, "earnings_date"),
+            [
+                Some(1738272600000),
+                Some(1730406600000),
+                Some(1714681800000)
+            ]
+        );
+        assert_eq!(
+            nullable(&df, "reported_eps"),
+            [None, Some(1.64), Some(1.53)]
+        );
+        assert!(matches!(
+            df.column("earnings_date").unwrap().dtype(),
+            DataType::Datetime(TimeUnit::Milliseconds, Some(tz)) if tz.as_str() == "America/New_York"
+        ));
+    }
 
-            let dict = PyDict::new(py);
-            dict.set_item("symbol", &symbol)?;
+    #[test]
+    fn earnings_dates_fall_back_to_utc() {
+        for timezone in [None, Some("Not/AZone")] {
+            let df = earnings_dates_frame(&json(EARNINGS_DATES), timezone, 10).unwrap();
+            assert!(matches!(
+                df.column("earnings_date").unwrap().dtype(),
+                DataType::Datetime(_, Some(tz)) if tz.as_str() == "UTC"
+            ));
+        }
+    }
 
-            // earnings_dates is Vec, not Option
-            let date_strs: Vec<String> = cal.earnings_dates.iter().map(|d| d.to_string()).collect();
-            dict.set_item("earningsDates", date_strs)?;
+    #[test]
+    fn earnings_dates_stop_at_limit() {
+        let df = earnings_dates_frame(&json(EARNINGS_DATES), None, 2).unwrap();
+        assert_eq!(
+            millis(&df, "earnings_date"),
+            [Some(1738272600000), Some(1730406600000)]
+        );
+    }
 
-            if let Some(date) = cal.ex_dividend_date {
-                dict.set_item("exDividendDate", date.to_string())?;
-            }
-            if let Some(date) = cal.dividend_payment_date {
-                dict.set_item("dividendDate", date.to_string())?;
-            }
+    #[test]
+    fn earnings_dates_of_an_empty_document_are_empty() {
+        let df = earnings_dates_frame(&serde_json::Value::Null, None, 10).unwrap();
+        assert_eq!(df.height(), 0);
+        assert_eq!(df.width(), 4);
+    }
 
-            Ok(dict.into())
-        })
+    const EARNINGS_TREND: &str = r#"{
+        "trend": [
+            {"period": "0q", "growth": {"raw": 0.05},
+             "earningsEstimate": {"avg": {"raw": 1.6}, "numberOfAnalysts": {"raw": 28}},
+             "epsRevisions": {"upLast7days": {"raw": 2}}},
+            {"period": "+1q"},
+            {"period": "+5y", "growth": {"raw": 0.12},
+             "earningsEstimate": {"avg": {"raw": 9.9}}}
+        ]
+    }"#;
+
+    #[test]
+    fn earnings_trend_keeps_estimate_periods() {
+        let df = earnings_trend_frame(
+            &json(EARNINGS_TREND),
+            "earningsEstimate",
+            EARNINGS_ESTIMATE_FIELDS,
+        )
+        .unwrap();
+
+        assert_eq!(df.width(), 1 + EARNINGS_ESTIMATE_FIELDS.len());
+        assert_eq!(
+            strs(&df, "period"),
+            [Some("0q".to_string()), Some("+1q".to_string())]
+        );
+        assert_eq!(nullable(&df, "avg"), [Some(1.6), None]);
+        // Analyst counts stay integers; a missing section leaves the row empty
+        assert_eq!(ints(&df, "number_of_analysts"), [Some(28), None]);
+
+        let revisions =
+            earnings_trend_frame(&json(EARNINGS_TREND), "epsRevisions", EPS_REVISIONS_FIELDS)
+                .unwrap();
+        assert_eq!(ints(&revisions, "up_last_7_days"), [Some(2), None]);
+        assert_eq!(ints(&revisions, "down_last_7_days"), [None, None]);
     }
 
-    // ============ Phase 3: Analysis & Holders ============
+    #[test]
+    fn growth_estimates_merge_stock_and_index_periods() {
+        let index = json(
+            r#"{"estimates": [
+                {"period": "0q", "growth": {"raw": 0.03}},
+                {"period": "+1y", "growth": {"raw": 0.08}}
+            ]}"#,
+        );
+        let df = growth_estimates_frame(&json(EARNINGS_TREND), &index).unwrap();
 
-    /// Get analyst recommendations as a Polars DataFrame
-    #[getter]
-    fn recommendations(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+        assert_eq!(
+            strs(&df, "period"),
+            ["0q", "+1q", "+5y", "+1y"].map(|p| Some(p.to_string()))
+        );
+        assert_eq!(
+            nullable(&df, "stock_trend"),
+            [Some(0.05), None, Some(0.12), None]
+        );
+        assert_eq!(
+            nullable(&df, "index_trend"),
+            [Some(0.03), None, None, Some(0.08)]
+        );
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let recs = ticker.recommendations().await.map_err(to_py_err)?;
-            let df = recs.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+        let df =
+            growth_estimates_frame(&serde_json::Value::Null, &serde_json::Value::Null).unwrap();
+        assert_eq!(df.height(), 0);
     }
 
-    /// Get analyst upgrades/downgrades history as a Polars DataFrame
-    #[getter]
-    fn upgrades_downgrades(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn price_targets_omit_missing_targets() {
+        let data = json(
+            r#"{"currentPrice": {"raw": 227.5, "fmt": "227.50"}, "targetHighPrice": {"raw": 300},
+                "targetLowPrice": {}, "targetMeanPrice": {"raw": 245.1}}"#,
+        );
+        let targets: Vec<(String, f64)> = price_targets(&data)
+            .0
+            .into_iter()
+            .map(|(key, value)| match value {
+                Value::Float(value) => (key, value),
+                _ => panic!("{key} is not a float"),
+            })
+            .collect();
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let data = ticker.upgrades_downgrades().await.map_err(to_py_err)?;
-            let df = data.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+        assert_eq!(
+            targets,
+            [
+                ("current".to_string(), 227.5),
+                ("high".to_string(), 300.0),
+                ("mean".to_string(), 245.1),
+            ]
+        );
+        assert!(price_targets(&serde_json::Value::Null).0.is_empty());
     }
 
-    /// Get major holders breakdown as a Polars DataFrame
-    #[getter]
-    fn major_holders(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn recommendations_summary_counts_ratings_per_period() {
+        let trend = json(
+            r#"{"trend": [
+                {"period": "0m", "strongBuy": 7, "buy": 21, "hold": 14, "sell": 2, "strongSell": 1},
+                {"period": "-1m", "strongBuy": 8, "buy": 20}
+            ]}"#,
+        );
+        let df = recommendations_summary_frame(&trend).unwrap();
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let holders = ticker.major_holders().await.map_err(to_py_err)?;
+        assert_eq!(
+            df.get_column_names(),
+            ["period", "strong_buy", "buy", "hold", "sell", "strong_sell"]
+        );
+        assert_eq!(
+            strs(&df, "period"),
+            [Some("0m".to_string()), Some("-1m".to_string())]
+        );
+        assert_eq!(ints(&df, "buy"), [Some(21), Some(20)]);
+        assert_eq!(ints(&df, "strong_sell"), [Some(1), None]);
 
-            let categories: Vec<String> = holders.iter().map(|h| h.category.clone()).collect();
-            let values: Vec<String> = holders.iter().map(|h| h.value.to_string()).collect();
+        let df = recommendations_summary_frame(&serde_json::Value::Null).unwrap();
+        assert_eq!(df.height(), 0);
+        assert_eq!(df.width(), 6);
+    }
 
-            let df = DataFrame::new(vec![
-                Series::new("Breakdown".into(), categories).into(),
-                Series::new("Value".into(), values).into(),
-            ])
-            .map_err(to_py_err)?;
+    const CALENDAR_EVENTS: &str = r#"{
+        "earnings": {
+            "earningsDate": [{"raw": 1738195200, "fmt": "2025-01-30"}, {"raw": 1738540800}],
+            "earningsAverage": {"raw": 2.35},
+            "earningsHigh": {},
+            "revenueAverage": {"raw": 124126000000}
+        },
+        "exDividendDate": {"raw": 1731024000}
+    }"#;
 
-            Ok(PyDataFrame(df))
-        })
+    #[test]
+    fn calendar_dict_omits_missing_dates_and_estimates() {
+        let Calendar::Dict(record) =
+            calendar(&json(CALENDAR_EVENTS), "AAPL".to_string(), false).unwrap()
+        else {
+            panic!("expected a dict");
+        };
+        let keys: Vec<&str> = record.0.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(
+            keys,
+            [
+                "symbol",
+                "earningsDates",
+                "exDividendDate",
+                "earningsAverage",
+                "revenueAverage"
+            ]
+        );
+        assert!(matches!(
+            &record.0[1].1,
+            Value::DateList(dates) if *dates == [day("2025-01-30"), day("2025-02-03")]
+        ));
+        assert!(matches!(&record.0[2].1, Value::Date(date) if *date == day("2024-11-08")));
     }
 
-    /// Get institutional holders as a Polars DataFrame
-    #[getter]
-    fn institutional_holders(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn calendar_frame_has_a_row_with_nulls_for_missing_fields() {
+        let Calendar::Frame(PyDataFrame(df)) =
+            calendar(&json(CALENDAR_EVENTS), "AAPL".to_string(), true).unwrap()
+        else {
+            panic!("expected a frame");
+        };
+        assert_eq!(df.height(), 1);
+        assert_eq!(df.width(), 4 + CALENDAR_ESTIMATES.len());
+        assert_eq!(
+            df.column("earnings_dates").unwrap().dtype(),
+            &DataType::List(Box::new(DataType::Date))
+        );
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let holders = ticker.institutional_holders().await.map_err(to_py_err)?;
-            let df = holders.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+        let dates = df
+            .column("earnings_dates")
+            .unwrap()
+            .list()
+            .unwrap()
+            .get_as_series(0)
+            .unwrap()
+            .cast(&DataType::Date)
+            .unwrap();
+        let dates: Vec<Option<NaiveDate>> = dates.date().unwrap().as_date_iter().collect();
+        assert_eq!(dates, [Some(day("2025-01-30")), Some(day("2025-02-03"))]);
+        let dividend: Vec<Option<NaiveDate>> = df
+            .column("dividend_date")
+            .unwrap()
+            .date()
+            .unwrap()
+            .as_date_iter()
+            .collect();
+        assert_eq!(dividend, [None]);
+        assert_eq!(nullable(&df, "earnings_average"), [Some(2.35)]);
+        assert_eq!(nullable(&df, "earnings_high"), [None]);
     }
 
-    /// Get mutual fund holders as a Polars DataFrame
-    #[getter]
-    fn mutualfund_holders(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn calendar_of_a_missing_module_has_only_the_symbol() {
+        let Calendar::Dict(record) =
+            calendar(&serde_json::Value::Null, "AAPL".to_string(), false).unwrap()
+        else {
+            panic!("expected a dict");
+        };
+        let keys: Vec<&str> = record.0.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, ["symbol", "earningsDates"]);
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let holders = ticker.mutual_fund_holders().await.map_err(to_py_err)?;
-            let df = holders.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+        let Calendar::Frame(PyDataFrame(df)) =
+            calendar(&serde_json::Value::Null, "AAPL".to_string(), true).unwrap()
+        else {
+            panic!("expected a frame");
+        };
+        assert_eq!(df.height(), 1);
+        assert_eq!(nullable(&df, "revenue_average"), [None]);
     }
 
-    /// Get insider transactions as a Polars DataFrame
-    #[getter]
-    fn insider_transactions(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn base_url_keeps_yahoo_paths_with_or_without_trailing_slash() {
+        for base in [
+            "http://127.0.0.1:8080/yahoo",
+            "http://127.0.0.1:8080/yahoo/",
+        ] {
+            let endpoints = resolve_endpoints(Some(base), None).unwrap();
+            assert_eq!(endpoints.len(), ENDPOINTS.len());
+            assert_eq!(
+                endpoint(&endpoints, "chart"),
+                Some("http://127.0.0.1:8080/yahoo/v8/finance/chart/")
+            );
+            assert_eq!(
+                endpoint(&endpoints, "timeseries"),
+                Some(
+                    "http://127.0.0.1:8080/yahoo/ws/fundamentals-timeseries/v1/finance/timeseries/"
+                )
+            );
+            assert_eq!(
+                endpoint(&endpoints, "crumb"),
+                Some("http://127.0.0.1:8080/yahoo/v1/test/getcrumb")
+            );
+        }
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let txns = ticker.insider_transactions().await.map_err(to_py_err)?;
-            let df = txns.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+    #[test]
+    fn base_urls_take_precedence_over_base_url() {
+        let overrides = HashMap::from([("quote".to_string(), "http://quotes.local/q".to_string())]);
+        let endpoints = resolve_endpoints(Some("http://mock.local"), Some(overrides)).unwrap();
+        assert_eq!(endpoint(&endpoints, "quote"), Some("http://quotes.local/q"));
+        assert_eq!(
+            endpoint(&endpoints, "quote_summary"),
+            Some("http://mock.local/v10/finance/quoteSummary/")
+        );
     }
 
-    /// Get insider roster holders as a Polars DataFrame
-    #[getter]
-    fn insider_roster_holders(&self) -> PyResult<PyDataFrame> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn base_urls_alone_override_only_their_endpoints() {
+        let overrides =
+            HashMap::from([("chart".to_string(), "http://mock.local/chart/".to_string())]);
+        let endpoints = resolve_endpoints(None, Some(overrides)).unwrap();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(
+            endpoint(&endpoints, "chart"),
+            Some("http://mock.local/chart/")
+        );
+        assert!(resolve_endpoints(None, None).unwrap().is_empty());
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let roster = ticker.insider_roster_holders().await.map_err(to_py_err)?;
-            let df = roster.to_dataframe().map_err(to_py_err)?;
-            Ok(PyDataFrame(df))
-        })
+    #[test]
+    fn endpoint_overrides_are_validated() {
+        let unknown = HashMap::from([("history".to_string(), "http://mock.local/".to_string())]);
+        assert!(resolve_endpoints(None, Some(unknown)).is_err());
+        let invalid = HashMap::from([("quote".to_string(), "not a url".to_string())]);
+        assert!(resolve_endpoints(None, Some(invalid)).is_err());
+        assert!(resolve_endpoints(Some("not a url"), None).is_err());
     }
 
-    // ============ Phase 4: Options ============
+    #[test]
+    fn parse_range_accepts_only_yfinance_periods() {
+        for period in VALID_PERIODS {
+            assert!(parse_range(period).is_ok(), "{}", period);
+        }
+        assert!(matches!(parse_range("ytd"), Ok(Range::Ytd)));
+        assert!(matches!(parse_range("10y"), Ok(Range::Y10)));
+        for period in ["", "1w", "2mo", "1Y", " 1d"] {
+            assert!(parse_range(period).is_err(), "{:?}", period);
+        }
+    }
 
-    /// Get available option expiration dates as a list of strings (YYYY-MM-DD)
-    #[getter]
-    fn options(&self) -> PyResult<Vec<String>> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
+    #[test]
+    fn parse_interval_accepts_only_yfinance_intervals() {
+        for interval in VALID_INTERVALS {
+            assert!(parse_interval(interval).is_ok(), "{}", interval);
+        }
+        assert!(matches!(parse_interval("60m"), Ok(Interval::I1h)));
+        assert!(matches!(parse_interval("1h"), Ok(Interval::I1h)));
+        for interval in ["", "4h", "1d ", "1M", "2wk"] {
+            assert!(parse_interval(interval).is_err(), "{:?}", interval);
+        }
+    }
 
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-            let timestamps = ticker.options().await.map_err(to_py_err)?;
+    #[test]
+    fn intraday_windows_respect_yahoo_limits() {
+        let now = utc("2024-06-30T00:00:00Z");
+        // (interval, start, end, accepted)
+        let cases = [
+            ("1m", "2024-06-23T00:00:00Z", "2024-06-30T00:00:00Z", true),
+            ("1m", "2024-06-22T23:59:00Z", "2024-06-30T00:00:00Z", false),
+            ("1m", "2024-05-31T00:00:00Z", "2024-06-07T00:00:00Z", true),
+            ("1m", "2024-05-30T23:00:00Z", "2024-06-05T00:00:00Z", false),
+            ("2m", "2024-05-01T00:00:00Z", "2024-06-30T00:00:00Z", true),
+            ("5m", "2024-04-30T23:00:00Z", "2024-05-10T00:00:00Z", false),
+            ("90m", "2024-05-15T00:00:00Z", "2024-06-30T00:00:00Z", true),
+            ("60m", "2022-07-01T00:00:00Z", "2024-06-30T00:00:00Z", true),
+            ("1h", "2022-06-30T00:00:00Z", "2022-07-30T00:00:00Z", false),
+            ("1d", "1990-01-01T00:00:00Z", "2024-06-30T00:00:00Z", true),
+            ("1wk", "1990-01-01T00:00:00Z", "2024-06-30T00:00:00Z", true),
+        ];
+        for (interval, start, end, accepted) in cases {
+            let result = validate_interval_window(interval, utc(start), utc(end), now);
+            assert_eq!(result.is_ok(), accepted, "{} {} - {}", interval, start, end);
+        }
+    }
 
-            // Convert Unix timestamps to YYYY-MM-DD strings
-            let dates: Vec<String> = timestamps
-                .iter()
-                .map(|ts| {
-                    chrono::DateTime::from_timestamp(*ts, 0)
-                        .map(|dt| dt.format("%Y-%m-%d").to_string())
-                        .unwrap_or_default()
-                })
-                .collect();
-
-            Ok(dates)
-        })
+    #[test]
+    fn intraday_history_defaults_to_a_window_yahoo_serves() {
+        let request = |period, interval, end: Option<DateTime<Utc>>| {
+            HistoryRequest::new(
+                period,
+                Some(interval),
+                None,
+                end.map(DateArg::Aware),
+                false,
+                true,
+                true,
+            )
+        };
+
+        // 1m bars are served at most 7 days at a time, so the default shrinks from 1mo to 5d
+        let req = request(None, "1m", None).unwrap();
+        assert!(matches!(req.range, Range::D5));
+        assert_eq!(req.window(Tz::UTC).unwrap(), None);
+        assert!(matches!(
+            request(None, "5m", None).unwrap().range,
+            Range::M1
+        ));
+        assert!(matches!(
+            request(None, "1d", None).unwrap().range,
+            Range::M1
+        ));
+        // An explicit period is not clamped
+        assert!(request(Some("1mo"), "1m", None).is_err());
+
+        // A lone end gets the same default window
+        let end = Utc::now() - TimeDelta::days(1);
+        let req = request(None, "1m", Some(end)).unwrap();
+        assert_eq!(
+            req.window(Tz::UTC).unwrap(),
+            Some((end - TimeDelta::days(5), end))
+        );
     }
 
-    /// Get option chain for a specific expiration date
-    ///
-    /// Args:
-    ///     date: Expiration date in YYYY-MM-DD format (optional, uses nearest if not provided)
-    ///
-    /// Returns:
-    ///     tuple: (calls DataFrame, puts DataFrame)
-    #[pyo3(signature = (date=None))]
-    fn option_chain(&self, date: Option<&str>) -> PyResult<(PyDataFrame, PyDataFrame)> {
-        let runtime = create_runtime()?;
-        let symbol = self.symbol.clone();
-        let date_str = date.map(|s| s.to_string());
-
-        runtime.block_on(async move {
-            let client = YfClient::default();
-            let ticker = YfTicker::new(&client, &symbol);
-
-            // Convert date string to Unix timestamp if provided
-            let timestamp = if let Some(ref date) = date_str {
-                let naive = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
-                    .map_err(|e| to_py_err(format!("Invalid date format: {}", e)))?;
-                Some(
-                    naive
-                        .and_hms_opt(0, 0, 0)
-                        .unwrap()
-                        .and_utc()
-                        .timestamp(),
-                )
-            } else {
-                None
-            };
+    #[test]
+    fn history_window_combines_period_with_one_date() {
+        let start = utc("2024-01-31T00:00:00Z");
+        let end = utc("2024-03-31T00:00:00Z");
+        // (period, start, end, expected window)
+        let cases = [
+            (None, None, None, None),
+            (Some("1y"), None, None, None),
+            (Some("max"), None, None, None),
+            (None, Some(start), Some(end), Some((start, end))),
+            (
+                Some("1mo"),
+                Some(start),
+                None,
+                Some((start, utc("2024-02-29T00:00:00Z"))),
+            ),
+            (
+                Some("5d"),
+                None,
+                Some(end),
+                Some((utc("2024-03-26T00:00:00Z"), end)),
+            ),
+            (
+                Some("1y"),
+                None,
+                Some(end),
+                Some((utc("2023-03-31T00:00:00Z"), end)),
+            ),
+            // A lone end defaults to one month of data
+            (
+                None,
+                None,
+                Some(end),
+                Some((utc("2024-02-29T00:00:00Z"), end)),
+            ),
+        ];
+        for (period, start, end, expected) in cases {
+            let window = resolve_history_window(period, start, end).unwrap();
+            assert_eq!(window, expected, "{:?} {:?} {:?}", period, start, end);
+        }
+    }
 
-            let chain = ticker.option_chain(timestamp).await.map_err(to_py_err)?;
+    #[test]
+    fn history_window_defaults_end_to_now() {
+        let start = utc("2024-01-02T00:00:00Z");
+        let (from, to) = resolve_history_window(None, Some(start), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(from, start);
+        assert!(to > start && to <= Utc::now());
+    }
 
-            let calls_df = chain.calls.to_dataframe().map_err(to_py_err)?;
-            let puts_df = chain.puts.to_dataframe().map_err(to_py_err)?;
+    #[test]
+    fn history_window_rejects_incompatible_arguments() {
+        let start = utc("2024-01-02T00:00:00Z");
+        let end = utc("2024-02-02T00:00:00Z");
+        let cases = [
+            (Some("1mo"), Some(start), Some(end)),
+            (Some("ytd"), Some(start), None),
+            (Some("max"), None, Some(end)),
+            (None, Some(end), Some(start)),
+            (None, Some(start), Some(start)),
+        ];
+        for (period, start, end) in cases {
+            assert!(
+                resolve_history_window(period, start, end).is_err(),
+                "{:?} {:?} {:?}",
+                period,
+                start,
+                end
+            );
+        }
+    }
 
-            Ok((PyDataFrame(calls_df), PyDataFrame(puts_df)))
-        })
+    fn local(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
     }
 
-    fn __repr__(&self) -> String {
-        format!("yfinance.Ticker('{}')", self.symbol)
+    #[test]
+    fn date_strings_keep_wall_clock_times() {
+        assert_eq!(
+            parse_date_str(" 2024-01-05 ").unwrap(),
+            DateBound::Local(local("2024-01-05 00:00:00"))
+        );
+        assert_eq!(
+            parse_date_str("2024-01-05 10:30:00").unwrap(),
+            DateBound::Local(local("2024-01-05 10:30:00"))
+        );
+        assert_eq!(
+            parse_date_str("2024-01-05T10:30:00+09:00").unwrap(),
+            DateBound::Instant(utc("2024-01-05T01:30:00Z"))
+        );
+        assert!(parse_date_str("05/01/2024").is_err());
     }
-}
 
-/// Python module for yfinance-pl
-#[pymodule]
-fn _yfinance_pl(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    m.add_class::<Ticker>()?;
-    Ok(())
+    #[test]
+    fn plain_dates_resolve_to_exchange_midnight() {
+        // (date, exchange timezone, UTC instant of local midnight)
+        let cases = [
+            ("2024-01-05", Tz::Asia__Tokyo, "2024-01-04T15:00:00Z"),
+            ("2024-07-04", Tz::America__New_York, "2024-07-04T04:00:00Z"),
+            ("2024-01-04", Tz::America__New_York, "2024-01-04T05:00:00Z"),
+            ("2024-01-05", Tz::UTC, "2024-01-05T00:00:00Z"),
+            // Midnight did not exist on the day Sao Paulo started DST in 2018
+            ("2018-11-04", Tz::America__Sao_Paulo, "2018-11-04T03:00:00Z"),
+        ];
+        for (date, tz, expected) in cases {
+            assert_eq!(
+                DateBound::Local(day(date).and_time(NaiveTime::MIN)).resolve(tz),
+                utc(expected),
+                "{} {}",
+                date,
+                tz
+            );
+        }
+
+        let instant = DateBound::Instant(utc("2024-01-05T10:30:00Z"));
+        assert_eq!(
+            instant.resolve(Tz::Asia__Tokyo),
+            utc("2024-01-05T10:30:00Z")
+        );
+    }
+
+    #[test]
+    fn naive_datetimes_resolve_like_plain_dates() {
+        // A date and the same naive midnight select the same window
+        let date = parse_date_str("2024-01-05").unwrap();
+        let midnight = parse_date_str("2024-01-05 00:00:00").unwrap();
+        for tz in [Tz::Asia__Tokyo, Tz::America__New_York] {
+            assert_eq!(date.resolve(tz), midnight.resolve(tz), "{}", tz);
+        }
+
+        // (exchange-local time, exchange timezone, UTC instant)
+        let cases = [
+            (
+                "2024-01-05 09:30:00",
+                Tz::America__New_York,
+                "2024-01-05T14:30:00Z",
+            ),
+            (
+                "2024-07-05 09:30:00",
+                Tz::America__New_York,
+                "2024-07-05T13:30:00Z",
+            ),
+            (
+                "2024-01-05 09:00:00",
+                Tz::Asia__Tokyo,
+                "2024-01-05T00:00:00Z",
+            ),
+            // 02:30 did not exist when New York moved its clocks forward
+            (
+                "2024-03-10 02:30:00",
+                Tz::America__New_York,
+                "2024-03-10T07:30:00Z",
+            ),
+        ];
+        for (time, tz, expected) in cases {
+            assert_eq!(
+                DateBound::Local(local(time)).resolve(tz),
+                utc(expected),
+                "{} {}",
+                time,
+                tz
+            );
+        }
+    }
 }
@@ -0,0 +1,245 @@
+//! Financial ratios derived per report date from the fundamentals timeseries.
+//!
+//! Every ratio is null when one of its line items is missing for the period or its denominator
+//! is zero, so a gap in Yahoo's data never turns into a misleading number.
+
+use crate::fundamentals::{Freq, Point, fetch_points};
+use crate::yahoo::Yahoo;
+use crate::{InvalidParameterError, json_f64, to_py_err};
+use chrono::{Days, NaiveDate};
+use polars::prelude::*;
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+
+/// Line items the ratios are computed from
+const RATIO_KEYS: &[&str] = &[
+    "TotalRevenue",
+    "GrossProfit",
+    "OperatingIncome",
+    "NetIncome",
+    "EBIT",
+    "InterestExpense",
+    "PretaxIncome",
+    "TaxProvision",
+    "TaxRateForCalcs",
+    "TotalAssets",
+    "CurrentAssets",
+    "CurrentLiabilities",
+    "StockholdersEquity",
+    "TotalDebt",
+    "InvestedCapital",
+    "FreeCashFlow",
+];
+
+/// Output columns, in the order returned by [`period_ratios`]
+const RATIO_NAMES: [&str; 11] = [
+    "gross_margin",
+    "operating_margin",
+    "net_margin",
+    "roe",
+    "roa",
+    "roic",
+    "current_ratio",
+    "debt_to_equity",
+    "interest_coverage",
+    "fcf_yield",
+    "asset_turnover",
+];
+
+/// `numerator / denominator`, or None when either is missing or the denominator is zero
+fn ratio(numerator: Option<f64>, denominator: Option<f64>) -> Option<f64> {
+    match (numerator, denominator) {
+        (Some(n), Some(d)) if d != 0.0 => Some(n / d),
+        _ => None,
+    }
+}
+
+/// Sum of a flow item over the four quarters ending at `date`, or None unless all four are
+/// reported. The window is a little under a year so a 52/53-week fiscal calendar still counts four.
+fn trailing_year(points: &[Point], key: &str, date: NaiveDate) -> Option<f64> {
+    let from = date - Days::new(350);
+    let quarters: Vec<&Point> = points
+        .iter()
+        .filter(|p| p.key == key && p.date > from && p.date <= date)
+        .collect();
+    let reported = quarters.iter().any(|p| p.date == date);
+    (reported && quarters.len() == 4).then(|| quarters.iter().map(|p| p.value).sum())
+}
+
+/// Ratios for one period; `get` looks up a line item for that period and `annual` a flow item
+/// over the year ending with it, so returns and turnover compare a year of flows with a balance
+fn period_ratios(
+    get: impl Fn(&str) -> Option<f64>,
+    annual: impl Fn(&str) -> Option<f64>,
+    market_cap: Option<f64>,
+) -> [Option<f64>; 11] {
+    let revenue = get("TotalRevenue");
+    let net_income = get("NetIncome");
+    let ebit = get("EBIT");
+    let annual_net_income = annual("NetIncome");
+    let equity = get("StockholdersEquity");
+    let assets = get("TotalAssets");
+
+    // NOPAT uses Yahoo's tax rate for calculations, falling back to the effective rate
+    let tax_rate =
+        get("TaxRateForCalcs").or_else(|| ratio(get("TaxProvision"), get("PretaxIncome")));
+    let nopat = annual("EBIT")
+        .zip(tax_rate)
+        .map(|(ebit, rate)| ebit * (1.0 - rate));
+
+    [
+        ratio(get("GrossProfit"), revenue),
+        ratio(get("OperatingIncome"), revenue),
+        ratio(net_income, revenue),
+        ratio(annual_net_income, equity),
+        ratio(annual_net_income, assets),
+        ratio(nopat, get("InvestedCapital")),
+        ratio(get("CurrentAssets"), get("CurrentLiabilities")),
+        ratio(get("TotalDebt"), equity),
+        // Yahoo reports interest expense as a positive amount
+        ratio(ebit, get("InterestExpense").map(f64::abs)),
+        ratio(annual("FreeCashFlow"), market_cap),
+        ratio(annual("TotalRevenue"), assets),
+    ]
+}
+
+/// One row of ratios per report date (newest first). Quarterly returns, turnover and `fcf_yield`
+/// use trailing-twelve-month flows. `fcf_yield` divides by the current market capitalisation, so
+/// it is only given for the latest period.
+pub(crate) async fn fetch_ratios(
+    yahoo: Yahoo,
+    symbol: String,
+    freq: Freq,
+) -> PyResult<PyDataFrame> {
+    if matches!(freq, Freq::Trailing) {
+        return Err(InvalidParameterError::new_err(
+            "freq='trailing' is not available for ratios (balance sheets have no trailing series)",
+        ));
+    }
+    let (points, quote) = futures::join!(
+        fetch_points(&yahoo, &symbol, RATIO_KEYS, freq, None, None),
+        yahoo.quote(&symbol)
+    );
+    let points: Vec<Point> = points?;
+    let market_cap = quote.ok().and_then(|q| json_f64(q.get("marketCap")));
+
+    let mut dates: Vec<NaiveDate> = points.iter().map(|p| p.date).collect();
+    dates.sort_unstable_by(|a, b| b.cmp(a));
+    dates.dedup();
+
+    let rows: Vec<[Option<f64>; 11]> = dates
+        .iter()
+        .enumerate()
+        .map(|(i, &date)| {
+            let get = |key: &str| {
+                points
+                    .iter()
+                    .find(|p| p.date == date && p.key == key)
+                    .map(|p| p.value)
+            };
+            let annual = |key: &str| match freq {
+                Freq::Quarterly => trailing_year(&points, key, date),
+                _ => get(key),
+            };
+            // Today's market cap only describes the latest period
+            period_ratios(get, annual, market_cap.filter(|_| i == 0))
+        })
+        .collect();
+
+    let mut columns = vec![Column::new("date".into(), &dates)];
+    for (i, name) in RATIO_NAMES.iter().enumerate() {
+        let values: Vec<Option<f64>> = rows.iter().map(|row| row[i]).collect();
+        columns.push(Column::new((*name).into(), values));
+    }
+    let df = DataFrame::new(columns).map_err(to_py_err)?;
+
+    Ok(PyDataFrame(df))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(key: &str, date: &str, value: f64) -> Point {
+        Point {
+            key: key.to_string(),
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            value,
+            currency: Some("USD".to_string()),
+        }
+    }
+
+    fn day(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn trailing_year_sums_four_quarters() {
+        let points = vec![
+            point("NetIncome", "2023-12-31", 1.0),
+            point("NetIncome", "2024-03-31", 2.0),
+            point("NetIncome", "2024-06-30", 3.0),
+            point("NetIncome", "2024-09-30", 4.0),
+            point("NetIncome", "2024-12-31", 5.0),
+            point("TotalRevenue", "2024-12-31", 100.0),
+        ];
+        let cases = [
+            ("2024-12-31", Some(14.0)),
+            ("2024-09-30", Some(10.0)),
+            // Only three quarters reported up to here
+            ("2024-06-30", None),
+            // No report at this date
+            ("2024-11-30", None),
+        ];
+        for (date, expected) in cases {
+            assert_eq!(
+                trailing_year(&points, "NetIncome", day(date)),
+                expected,
+                "{date}"
+            );
+        }
+    }
+
+    #[test]
+    fn trailing_year_handles_52_53_week_fiscal_years() {
+        // Quarter ends drift by a few days; the quarter a year back must not be counted
+        let points = vec![
+            point("NetIncome", "2023-09-30", 9.0),
+            point("NetIncome", "2023-12-30", 1.0),
+            point("NetIncome", "2024-03-30", 1.0),
+            point("NetIncome", "2024-06-29", 1.0),
+            point("NetIncome", "2024-09-28", 1.0),
+        ];
+        assert_eq!(
+            trailing_year(&points, "NetIncome", day("2024-09-28")),
+            Some(4.0)
+        );
+    }
+
+    #[test]
+    fn period_ratios_use_annual_flows_for_returns() {
+        let quarter = |key: &str| match key {
+            "TotalRevenue" => Some(100.0),
+            "NetIncome" => Some(10.0),
+            "StockholdersEquity" => Some(200.0),
+            "TotalAssets" => Some(400.0),
+            _ => None,
+        };
+        let annual = |key: &str| match key {
+            "TotalRevenue" => Some(400.0),
+            "NetIncome" => Some(40.0),
+            _ => None,
+        };
+        let ratios = period_ratios(quarter, annual, None);
+        let by_name = |name: &str| ratios[RATIO_NAMES.iter().position(|n| *n == name).unwrap()];
+
+        assert_eq!(by_name("net_margin"), Some(0.1));
+        assert_eq!(by_name("roe"), Some(0.2));
+        assert_eq!(by_name("roa"), Some(0.1));
+        assert_eq!(by_name("asset_turnover"), Some(1.0));
+        // Missing line items or market cap give nulls, not zeros
+        assert_eq!(by_name("roic"), None);
+        assert_eq!(by_name("fcf_yield"), None);
+        assert_eq!(by_name("current_ratio"), None);
+    }
+}
@@ -0,0 +1,454 @@
+//! Raw Yahoo Finance JSON endpoints for data yfinance-rs does not expose in typed form
+//! (quoteSummary modules, quote and chart metadata, earnings calendar queries and
+//! fundamentals timeseries).
+//!
+//! Requests go through their own HTTP client, built from the same `Client` settings (proxy,
+//! timeouts, user agent and endpoint overrides) as the yfinance-rs client, and retried under
+//! the same yfinance-rs `RetryConfig`. Only the cookie/crumb session is separate: yfinance-rs
+//! does not share its session, so this client performs its own handshake on first use.
+
+use crate::{
+    AuthenticationError, DataParseError, InvalidParameterError, NetworkError, RateLimitError,
+    TickerNotFoundError, is_not_found, parse_err,
+};
+use chrono::{DateTime, Utc};
+use pyo3::prelude::*;
+use reqwest::{Response, StatusCode};
+use serde_json::{Map, Value as Json};
+use std::hash::{BuildHasher, RandomState};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::Mutex;
+use url::Url;
+use yfinance_rs::core::client::{Backoff, RetryConfig};
+
+const CHART_URL: &str = "https://query1.finance.yahoo.com/v8/finance/chart/";
+const QUOTE_SUMMARY_URL: &str = "https://query2.finance.yahoo.com/v10/finance/quoteSummary/";
+const QUOTE_URL: &str = "https://query1.finance.yahoo.com/v7/finance/quote";
+const TIMESERIES_URL: &str =
+    "https://query2.finance.yahoo.com/ws/fundamentals-timeseries/v1/finance/timeseries/";
+const VISUALIZATION_URL: &str = "https://query1.finance.yahoo.com/v1/finance/visualization";
+const COOKIE_URL: &str = "https://fc.yahoo.com/consent";
+const CRUMB_URL: &str = "https://query1.finance.yahoo.com/v1/test/getcrumb";
+/// Request timeouts used when `Client` is given none, for both HTTP clients, so a stalled
+/// connection fails into the retry path instead of hanging
+pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+pub(crate) const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
+    (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36";
+
+/// Settings mirrored from `Client`
+#[derive(Default)]
+pub(crate) struct YahooConfig {
+    pub proxy: Option<String>,
+    pub timeout: Option<Duration>,
+    pub connect_timeout: Option<Duration>,
+    /// Shared with the yfinance-rs client
+    pub retry: RetryConfig,
+    pub user_agent: Option<String>,
+    /// Endpoint overrides as resolved by `resolve_endpoints`
+    pub endpoints: Vec<(&'static str, Url)>,
+}
+
+/// Cheaply cloneable handle to the raw Yahoo Finance endpoints
+#[derive(Clone)]
+pub(crate) struct Yahoo(Arc<Inner>);
+
+struct Inner {
+    http: reqwest::Client,
+    chart: Url,
+    quote_summary: Url,
+    quote: Url,
+    timeseries: Url,
+    visualization: Url,
+    cookie: Url,
+    crumb_url: Url,
+    retry: RetryConfig,
+    crumb: Mutex<Option<String>>,
+}
+
+fn network_err(e: reqwest::Error) -> PyErr {
+    NetworkError::new_err(e.to_string())
+}
+
+/// Seconds to wait from a Retry-After header, given either as seconds or as an HTTP date
+fn retry_after_secs(value: &str, now: DateTime<Utc>) -> Option<f64> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<f64>() {
+        return Some(secs.max(0.0));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((at.with_timezone(&Utc) - now).num_milliseconds().max(0) as f64 / 1000.0)
+}
+
+/// Delay before retry number `attempt` (from 0) under a yfinance-rs backoff policy; jitter
+/// scales the delay by a random factor between 0.5 and 1
+fn retry_delay(backoff: &Backoff, attempt: u32) -> Duration {
+    match backoff {
+        Backoff::Fixed(delay) => *delay,
+        Backoff::Exponential {
+            base,
+            factor,
+            max,
+            jitter,
+        } => {
+            let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+            let mut secs = (base.as_secs_f64() * factor.powi(exponent)).min(max.as_secs_f64());
+            if *jitter {
+                secs *= 0.5 + 0.5 * random_fraction();
+            }
+            Duration::try_from_secs_f64(secs).unwrap_or(*max)
+        }
+    }
+}
+
+/// A pseudo-random number in [0, 1), seeded by std's per-process hasher keys
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().hash_one(SystemTime::now());
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Map an unsuccessful response onto the matching exception class
+async fn status_err(resp: Response, symbol: &str) -> PyErr {
+    let status = resp.status();
+    let retry_after = resp
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| retry_after_secs(v, Utc::now()));
+    let body = resp.text().await.unwrap_or_default();
+    let description = serde_json::from_str::<Json>(&body)
+        .ok()
+        .and_then(|json| {
+            json.pointer("/quoteSummary/error/description")
+                .or_else(|| json.pointer("/chart/error/description"))
+                .or_else(|| json.pointer("/finance/error/description"))
+                .and_then(Json::as_str)
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| status.to_string());
+
+    match status {
+        StatusCode::NOT_FOUND => {
+            TickerNotFoundError::new_err(format!("{}: {}", symbol, description))
+        }
+        StatusCode::TOO_MANY_REQUESTS => RateLimitError::new_err((
+            format!("Rate limited by Yahoo Finance: {}", description),
+            retry_after,
+        )),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => AuthenticationError::new_err(format!(
+            "Yahoo Finance rejected the request: {}",
+            description
+        )),
+        _ => NetworkError::new_err(format!("HTTP {} for {}: {}", status, symbol, description)),
+    }
+}
+
+impl Yahoo {
+    pub(crate) fn new(config: YahooConfig) -> PyResult<Self> {
+        let mut builder = reqwest::Client::builder()
+            .cookie_store(true)
+            .user_agent(config.user_agent.as_deref().unwrap_or(USER_AGENT));
+        if let Some(proxy) = &config.proxy {
+            let proxy = reqwest::Proxy::all(proxy).map_err(|e| {
+                InvalidParameterError::new_err(format!("Invalid proxy '{}': {}", proxy, e))
+            })?;
+            builder = builder.proxy(proxy);
+        }
+        builder = builder
+            .timeout(config.timeout.unwrap_or(DEFAULT_TIMEOUT))
+            .connect_timeout(config.connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT));
+        let http = builder.build().map_err(network_err)?;
+
+        let endpoint = |name: &str, default: &str| {
+            config
+                .endpoints
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map_or_else(
+                    || Url::parse(default).expect("default endpoint URLs are valid"),
+                    |(_, url)| url.clone(),
+                )
+        };
+
+        Ok(Yahoo(Arc::new(Inner {
+            http,
+            chart: endpoint("chart", CHART_URL),
+            quote_summary: endpoint("quote_summary", QUOTE_SUMMARY_URL),
+            quote: endpoint("quote", QUOTE_URL),
+            timeseries: endpoint("timeseries", TIMESERIES_URL),
+            visualization: endpoint("visualization", VISUALIZATION_URL),
+            cookie: endpoint("cookie", COOKIE_URL),
+            crumb_url: endpoint("crumb", CRUMB_URL),
+            retry: config.retry,
+            crumb: Mutex::new(None),
+        })))
+    }
+
+    /// Crumb for authenticated requests, fetched once per client (or again after a 401)
+    async fn crumb(&self, refresh: bool) -> PyResult<String> {
+        let mut crumb = self.0.crumb.lock().await;
+        if refresh {
+            *crumb = None;
+        }
+        if let Some(crumb) = crumb.as_ref() {
+            return Ok(crumb.clone());
+        }
+
+        // The cookie endpoint only sets session cookies; its status code does not matter
+        let _ = self.0.http.get(self.0.cookie.clone()).send().await;
+        let resp = self
+            .0
+            .http
+            .get(self.0.crumb_url.clone())
+            .send()
+            .await
+            .map_err(network_err)?;
+        if !resp.status().is_success() {
+            return Err(status_err(resp, "crumb").await);
+        }
+        let text = resp.text().await.map_err(network_err)?;
+        let text = text.trim();
+        if text.is_empty() || text.contains(char::is_whitespace) || text.contains('<') {
+            return Err(AuthenticationError::new_err(
+                "Could not obtain a crumb from Yahoo Finance",
+            ));
+        }
+        *crumb = Some(text.to_string());
+        Ok(text.to_string())
+    }
+
+    async fn get_json(&self, url: Url, symbol: &str) -> PyResult<Json> {
+        self.send_json(|http| http.get(url.clone()), symbol).await
+    }
+
+    /// Send a request built by `request` and parse the JSON response, retrying rate limits,
+    /// server errors and timeouts under the shared retry policy and refreshing the crumb once if
+    /// it was rejected
+    async fn send_json<F>(&self, request: F, symbol: &str) -> PyResult<Json>
+    where
+        F: Fn(&reqwest::Client) -> reqwest::RequestBuilder,
+    {
+        let retries = if self.0.retry.enabled {
+            self.0.retry.max_retries
+        } else {
+            0
+        };
+        let mut attempt = 0;
+        let mut refresh = false;
+        loop {
+            let crumb = self.crumb(refresh).await?;
+            let result = request(&self.0.http)
+                .query(&[("crumb", crumb)])
+                .send()
+                .await;
+
+            match result {
+                Ok(resp) if resp.status().is_success() => {
+                    return resp.json().await.map_err(parse_err);
+                }
+                Ok(resp) if resp.status() == StatusCode::UNAUTHORIZED && !refresh => {
+                    refresh = true;
+                    continue;
+                }
+                Ok(resp) => {
+                    let status = resp.status();
+                    let transient =
+                        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+                    if !transient || attempt >= retries {
+                        return Err(status_err(resp, symbol).await);
+                    }
+                }
+                Err(e) if attempt < retries && (e.is_timeout() || e.is_connect()) => {}
+                Err(e) => return Err(network_err(e)),
+            }
+
+            tokio::time::sleep(retry_delay(&self.0.retry.backoff, attempt)).await;
+            attempt += 1;
+            refresh = false;
+        }
+    }
+
+    /// Fetch quoteSummary modules for a symbol, returned as module objects keyed by module name
+    pub(crate) async fn quote_summary(
+        &self,
+        symbol: &str,
+        modules: &[&str],
+    ) -> PyResult<Map<String, Json>> {
+        let mut url = self.0.quote_summary.join(symbol).map_err(|e| {
+            InvalidParameterError::new_err(format!("Invalid symbol '{}': {}", symbol, e))
+        })?;
+        url.query_pairs_mut()
+            .append_pair("modules", &modules.join(","))
+            .append_pair("formatted", "false");
+
+        let mut json = self.get_json(url, symbol).await?;
+        let summary = json
+            .get_mut("quoteSummary")
+            .ok_or_else(|| DataParseError::new_err("quoteSummary response without quoteSummary"))?;
+        let description = summary
+            .pointer("/error/description")
+            .and_then(Json::as_str)
+            .map(str::to_string);
+
+        match summary.pointer_mut("/result/0").map(Json::take) {
+            Some(Json::Object(result)) => Ok(result),
+            _ => {
+                let description =
+                    description.unwrap_or_else(|| "no quoteSummary result".to_string());
+                if is_not_found(&description) {
+                    Err(TickerNotFoundError::new_err(format!(
+                        "{}: {}",
+                        symbol, description
+                    )))
+                } else {
+                    Err(DataParseError::new_err(format!(
+                        "{}: {}",
+                        symbol, description
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Fetch the v7 quote for a symbol
+    pub(crate) async fn quote(&self, symbol: &str) -> PyResult<Map<String, Json>> {
+        let mut url = self.0.quote.clone();
+        url.query_pairs_mut().append_pair("symbols", symbol);
+
+        let mut json = self.get_json(url, symbol).await?;
+        match json.pointer_mut("/quoteResponse/result/0").map(Json::take) {
+            Some(Json::Object(quote)) => Ok(quote),
+            _ => Err(TickerNotFoundError::new_err(format!(
+                "{}: no quote found",
+                symbol
+            ))),
+        }
+    }
+
+    /// Fetch a v8 chart (e.g. `&[("range", "1y"), ("interval", "1d")]`), returning its result object
+    pub(crate) async fn chart(
+        &self,
+        symbol: &str,
+        params: &[(&str, &str)],
+    ) -> PyResult<Map<String, Json>> {
+        let mut url = self.0.chart.join(symbol).map_err(|e| {
+            InvalidParameterError::new_err(format!("Invalid symbol '{}': {}", symbol, e))
+        })?;
+        url.query_pairs_mut().extend_pairs(params);
+
+        let mut json = self.get_json(url, symbol).await?;
+        match json.pointer_mut("/chart/result/0").map(Json::take) {
+            Some(Json::Object(chart)) => Ok(chart),
+            _ => Err(TickerNotFoundError::new_err(format!(
+                "{}: no chart data found",
+                symbol
+            ))),
+        }
+    }
+
+    /// Fetch fundamentals-timeseries `types` (e.g. `annualTotalRevenue`) reported between two Unix
+    /// timestamps, returning one result object per type that has data
+    pub(crate) async fn timeseries(
+        &self,
+        symbol: &str,
+        types: &[String],
+        period1: i64,
+        period2: i64,
+    ) -> PyResult<Vec<Map<String, Json>>> {
+        let mut url = self.0.timeseries.join(symbol).map_err(|e| {
+            InvalidParameterError::new_err(format!("Invalid symbol '{}': {}", symbol, e))
+        })?;
+        url.query_pairs_mut()
+            .append_pair("symbol", symbol)
+            .append_pair("type", &types.join(","))
+            .append_pair("period1", &period1.to_string())
+            .append_pair("period2", &period2.to_string());
+
+        let mut json = self.get_json(url, symbol).await?;
+        let results = match json.pointer_mut("/timeseries/result").map(Json::take) {
+            Some(Json::Array(results)) => results,
+            _ => {
+                return Err(DataParseError::new_err(format!(
+                    "{}: timeseries response without result",
+                    symbol
+                )));
+            }
+        };
+
+        Ok(results
+            .into_iter()
+            .filter_map(|result| match result {
+                Json::Object(result) => Some(result),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Run a visualization query (used for the earnings calendar), returning its first document
+    /// (null when there is none)
+    pub(crate) async fn visualization(&self, symbol: &str, query: &Json) -> PyResult<Json> {
+        let url = self.0.visualization.clone();
+        let mut json = self
+            .send_json(|http| http.post(url.clone()).json(query), symbol)
+            .await?;
+
+        Ok(json
+            .pointer_mut("/finance/result/0/documents/0")
+            .map(Json::take)
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_follows_the_backoff_policy() {
+        let fixed = Backoff::Fixed(Duration::from_millis(250));
+        assert_eq!(retry_delay(&fixed, 0), Duration::from_millis(250));
+        assert_eq!(retry_delay(&fixed, 5), Duration::from_millis(250));
+
+        let exponential = |jitter| Backoff::Exponential {
+            base: Duration::from_millis(500),
+            factor: 2.0,
+            max: Duration::from_secs(3),
+            jitter,
+        };
+        let delays: Vec<Duration> = (0..5)
+            .map(|n| retry_delay(&exponential(false), n))
+            .collect();
+        assert_eq!(
+            delays,
+            [500, 1000, 2000, 3000, 3000].map(Duration::from_millis)
+        );
+        assert_eq!(
+            retry_delay(&exponential(false), u32::MAX),
+            Duration::from_secs(3)
+        );
+
+        for attempt in 0..5 {
+            let full = retry_delay(&exponential(false), attempt);
+            let jittered = retry_delay(&exponential(true), attempt);
+            assert!(jittered >= full / 2 && jittered <= full, "{:?}", jittered);
+        }
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_http_dates() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:27:30Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let cases = [
+            ("120", Some(120.0)),
+            (" 1.5 ", Some(1.5)),
+            ("Wed, 21 Oct 2015 07:28:00 GMT", Some(30.0)),
+            ("Wed, 21 Oct 2015 07:00:00 GMT", Some(0.0)),
+            ("soon", None),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(retry_after_secs(value, now), expected, "{:?}", value);
+        }
+    }
+}